@@ -0,0 +1,91 @@
+use std::fmt;
+
+pub use self::types::Tag;
+
+mod types;
+
+pub const TAG_LEN: usize = 16;
+
+// shared by every AEAD in the crate, so callers can handle failures without
+// knowing which construction produced them
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    InvalidIvLength,
+    PlaintextTooLong,
+    AadTooLong,
+    InvalidTag,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::InvalidIvLength => "aead iv length is not supported",
+            Self::PlaintextTooLong => "aead plaintext exceeds the maximum length",
+            Self::AadTooLong => "aead associated data exceeds the maximum length",
+            Self::InvalidTag => "aead authentication tag mismatch",
+        };
+
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+// Authenticated encryption with associated data. The detached methods work
+// in place and keep the tag apart, `seal` and `open` append it to the
+// ciphertext. A failed decryption never releases plaintext.
+pub trait Aead {
+    const KEY_LEN: usize;
+    const NONCE_LEN: usize;
+    const TAG_LEN: usize;
+
+    type Key;
+    type Nonce;
+    type Tag: Default + AsRef<[u8]> + AsMut<[u8]>;
+
+    fn new(key: &Self::Key) -> Self
+    where
+        Self: Sized;
+
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Self::Nonce,
+        aad: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Self::Tag, Error>;
+
+    // the buffer is left untouched when authentication fails
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Self::Nonce,
+        aad: &[u8],
+        buffer: &mut [u8],
+        tag: &Self::Tag,
+    ) -> Result<(), Error>;
+
+    // returns the ciphertext followed by the tag
+    fn seal(&self, nonce: &Self::Nonce, aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut buffer = Vec::with_capacity(plaintext.len() + Self::TAG_LEN);
+        buffer.extend_from_slice(plaintext);
+
+        let tag = self.encrypt_in_place_detached(nonce, aad, &mut buffer)?;
+        buffer.extend_from_slice(tag.as_ref());
+
+        Ok(buffer)
+    }
+
+    fn open(&self, nonce: &Self::Nonce, aad: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, Error> {
+        let Some(split) = ciphertext.len().checked_sub(Self::TAG_LEN) else {
+            return Err(Error::InvalidTag);
+        };
+
+        let (ciphertext, tag_bytes) = ciphertext.split_at(split);
+        let mut tag = Self::Tag::default();
+        tag.as_mut().copy_from_slice(tag_bytes);
+
+        let mut buffer = ciphertext.to_vec();
+        self.decrypt_in_place_detached(nonce, aad, &mut buffer, &tag)?;
+
+        Ok(buffer)
+    }
+}
@@ -0,0 +1,17 @@
+use super::TAG_LEN;
+use crate::ct::ConstantTimeEq;
+
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct Tag([u8; TAG_LEN]);
+
+// tags are compared in constant time
+impl PartialEq for Tag {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other)
+    }
+}
+
+impl Eq for Tag {}
+
+bytes_wrapper_impl!(Tag, TAG_LEN, public);
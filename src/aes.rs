@@ -0,0 +1,198 @@
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+pub use self::consts::{BLOCK_LEN, KEY128_LEN, KEY192_LEN, KEY256_LEN};
+pub use self::types::{Key128, Key192, Key256};
+
+use self::consts::*;
+#[cfg(target_arch = "x86_64")]
+use self::ni::NiAes;
+use self::soft::SoftAes;
+
+pub(crate) mod consts;
+#[cfg(target_arch = "x86_64")]
+mod ni;
+mod soft;
+mod types;
+
+// A cipher on 128-bit blocks. Multi-block calls let backends process several
+// independent blocks at once.
+pub trait BlockCipher {
+    const KEY_LEN: usize;
+
+    type Key;
+
+    fn new(key: &Self::Key) -> Self
+    where
+        Self: Sized;
+
+    fn encrypt_blocks(&self, blocks: &mut [[u8; BLOCK_LEN]]);
+
+    fn decrypt_blocks(&self, blocks: &mut [[u8; BLOCK_LEN]]);
+
+    #[inline]
+    fn encrypt_block(&self, block: &mut [u8; BLOCK_LEN]) {
+        self.encrypt_blocks(std::slice::from_mut(block));
+    }
+
+    #[inline]
+    fn decrypt_block(&self, block: &mut [u8; BLOCK_LEN]) {
+        self.decrypt_blocks(std::slice::from_mut(block));
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    // bitsliced software implementation, constant-time on any target
+    Soft,
+    // x86_64 AES instructions, selected at runtime when available
+    AesNi,
+}
+
+// both backends zeroize their round keys on drop; the size difference is
+// accepted to keep the cipher free of allocations
+#[derive(Clone)]
+#[allow(clippy::large_enum_variant)]
+enum Inner {
+    Soft(SoftAes),
+    #[cfg(target_arch = "x86_64")]
+    AesNi(NiAes),
+}
+
+impl Inner {
+    fn new(key: &[u8], detect: bool) -> Self {
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+        let (mut round_keys, rounds) = expand_key(key);
+
+        let inner = Self::from_round_keys(&round_keys[..=rounds], detect);
+
+        #[cfg(feature = "zeroize")]
+        round_keys.zeroize();
+
+        inner
+    }
+
+    fn from_round_keys(round_keys: &[[u8; BLOCK_LEN]], detect: bool) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        if detect {
+            if let Some(ni) = NiAes::new(round_keys) {
+                return Self::AesNi(ni);
+            }
+        }
+        #[cfg(not(target_arch = "x86_64"))]
+        let _ = detect;
+
+        Self::Soft(SoftAes::new(round_keys))
+    }
+
+    fn backend(&self) -> Backend {
+        match self {
+            Self::Soft(_) => Backend::Soft,
+            #[cfg(target_arch = "x86_64")]
+            Self::AesNi(_) => Backend::AesNi,
+        }
+    }
+
+    #[inline]
+    fn encrypt_blocks(&self, blocks: &mut [[u8; BLOCK_LEN]]) {
+        match self {
+            Self::Soft(soft) => soft.encrypt_blocks(blocks),
+            #[cfg(target_arch = "x86_64")]
+            Self::AesNi(ni) => ni.encrypt_blocks(blocks),
+        }
+    }
+
+    #[inline]
+    fn decrypt_blocks(&self, blocks: &mut [[u8; BLOCK_LEN]]) {
+        match self {
+            Self::Soft(soft) => soft.decrypt_blocks(blocks),
+            #[cfg(target_arch = "x86_64")]
+            Self::AesNi(ni) => ni.decrypt_blocks(blocks),
+        }
+    }
+}
+
+// FIPS-197 section 5.2, returns the round keys and the number of rounds
+fn expand_key(key: &[u8]) -> ([[u8; BLOCK_LEN]; MAX_ROUNDS + 1], usize) {
+    let nk = key.len() / 4;
+    let rounds = nk + 6;
+
+    let mut words = [0_u32; 4 * (MAX_ROUNDS + 1)];
+    crate::utils::bytes_to_words(key, &mut words[..nk]);
+
+    for i in nk..4 * (rounds + 1) {
+        let mut temp = words[i - 1];
+
+        if i % nk == 0 {
+            // RotWord is a rotation by one byte of the little-endian word
+            temp = soft::sub_word(temp.rotate_right(8)) ^ u32::from(RCON[i / nk - 1]);
+        } else if nk > 6 && i % nk == 4 {
+            temp = soft::sub_word(temp);
+        }
+
+        words[i] = words[i - nk] ^ temp;
+    }
+
+    let mut round_keys = [[0; BLOCK_LEN]; MAX_ROUNDS + 1];
+    for (round_key, chunk) in round_keys.iter_mut().zip(words.chunks_exact(4)) {
+        crate::utils::words_to_bytes(chunk, round_key);
+    }
+
+    #[cfg(feature = "zeroize")]
+    words.zeroize();
+
+    (round_keys, rounds)
+}
+
+macro_rules! aes_impl {
+    ($name:ident, $key:ident, $key_len:ident) => {
+        #[derive(Clone)]
+        pub struct $name(Inner);
+
+        impl $name {
+            // uses AES-NI when the CPU supports it, the software backend
+            // otherwise
+            pub fn new(key: &$key) -> Self {
+                Self(Inner::new(key.bytes(), true))
+            }
+
+            pub fn new_soft(key: &$key) -> Self {
+                Self(Inner::new(key.bytes(), false))
+            }
+
+            // checks the length of untrusted key bytes
+            pub fn new_from_slice(key: &[u8]) -> Result<Self, crate::Error> {
+                Ok(Self::new(&$key::try_from(key)?))
+            }
+
+            pub fn backend(&self) -> Backend {
+                self.0.backend()
+            }
+        }
+
+        impl BlockCipher for $name {
+            const KEY_LEN: usize = $key_len;
+
+            type Key = $key;
+
+            #[inline]
+            fn new(key: &$key) -> Self {
+                Self::new(key)
+            }
+
+            #[inline]
+            fn encrypt_blocks(&self, blocks: &mut [[u8; BLOCK_LEN]]) {
+                self.0.encrypt_blocks(blocks);
+            }
+
+            #[inline]
+            fn decrypt_blocks(&self, blocks: &mut [[u8; BLOCK_LEN]]) {
+                self.0.decrypt_blocks(blocks);
+            }
+        }
+    };
+}
+
+aes_impl!(Aes128, Key128, KEY128_LEN);
+aes_impl!(Aes192, Key192, KEY192_LEN);
+aes_impl!(Aes256, Key256, KEY256_LEN);
@@ -0,0 +1,10 @@
+pub const BLOCK_LEN: usize = 16;
+
+pub const KEY128_LEN: usize = 16;
+pub const KEY192_LEN: usize = 24;
+pub const KEY256_LEN: usize = 32;
+
+pub const MAX_ROUNDS: usize = 14;
+
+// x^(i - 1) in GF(2^8), enough for the ten AES-128 expansion steps
+pub(crate) const RCON: [u8; 10] = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1b, 0x36];
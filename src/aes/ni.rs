@@ -0,0 +1,125 @@
+use std::arch::x86_64::*;
+
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use super::consts::*;
+
+// AES-NI backend. It can only be constructed on CPUs that report the AES
+// instructions, which is what makes the calls into the target feature
+// functions below sound.
+#[derive(Clone)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub(crate) struct NiAes {
+    enc_keys: [[u8; BLOCK_LEN]; MAX_ROUNDS + 1],
+    // round keys of the equivalent inverse cipher, in decryption order
+    dec_keys: [[u8; BLOCK_LEN]; MAX_ROUNDS + 1],
+    rounds: usize,
+}
+
+impl NiAes {
+    pub(crate) fn is_supported() -> bool {
+        std::arch::is_x86_feature_detected!("aes")
+    }
+
+    pub(crate) fn new(round_keys: &[[u8; BLOCK_LEN]]) -> Option<Self> {
+        if !Self::is_supported() {
+            return None;
+        }
+
+        let rounds = round_keys.len() - 1;
+        let mut enc_keys = [[0; BLOCK_LEN]; MAX_ROUNDS + 1];
+        let mut dec_keys = [[0; BLOCK_LEN]; MAX_ROUNDS + 1];
+
+        enc_keys[..=rounds].copy_from_slice(round_keys);
+
+        dec_keys[0] = round_keys[rounds];
+        dec_keys[rounds] = round_keys[0];
+        for i in 1..rounds {
+            // SAFETY: `is_supported` ran is_x86_feature_detected!("aes") above
+            unsafe { inv_mix_columns(&round_keys[rounds - i], &mut dec_keys[i]) };
+        }
+
+        Some(Self {
+            enc_keys,
+            dec_keys,
+            rounds,
+        })
+    }
+
+    pub(crate) fn encrypt_blocks(&self, blocks: &mut [[u8; BLOCK_LEN]]) {
+        // SAFETY: Self is only built by `new`, after is_x86_feature_detected!("aes")
+        unsafe { encrypt_blocks(&self.enc_keys[..=self.rounds], blocks) };
+    }
+
+    pub(crate) fn decrypt_blocks(&self, blocks: &mut [[u8; BLOCK_LEN]]) {
+        // SAFETY: Self is only built by `new`, after is_x86_feature_detected!("aes")
+        unsafe { decrypt_blocks(&self.dec_keys[..=self.rounds], blocks) };
+    }
+}
+
+#[inline(always)]
+fn load(bytes: &[u8; BLOCK_LEN]) -> __m128i {
+    // SAFETY: the pointer is valid for 16 bytes and loadu has no alignment
+    // requirement, sse2 is part of the x86_64 baseline
+    unsafe { _mm_loadu_si128(bytes.as_ptr().cast()) }
+}
+
+#[inline(always)]
+fn store(bytes: &mut [u8; BLOCK_LEN], value: __m128i) {
+    // SAFETY: as in `load`
+    unsafe { _mm_storeu_si128(bytes.as_mut_ptr().cast(), value) }
+}
+
+// callers must have checked that the CPU supports aes
+#[target_feature(enable = "aes")]
+// the intrinsics only became safe to call here in Rust 1.87
+#[allow(unused_unsafe)]
+unsafe fn inv_mix_columns(key: &[u8; BLOCK_LEN], dst: &mut [u8; BLOCK_LEN]) {
+    // SAFETY: aes is enabled for this function
+    store(dst, unsafe { _mm_aesimc_si128(load(key)) });
+}
+
+// callers must have checked that the CPU supports aes
+#[target_feature(enable = "aes")]
+// the intrinsics only became safe to call here in Rust 1.87
+#[allow(unused_unsafe)]
+unsafe fn encrypt_blocks(keys: &[[u8; BLOCK_LEN]], blocks: &mut [[u8; BLOCK_LEN]]) {
+    let (last, middle) = keys[1..].split_last().unwrap();
+
+    for block in blocks {
+        // SAFETY: aes is enabled for this function and sse2 is baseline
+        let state = unsafe {
+            let mut state = _mm_xor_si128(load(block), load(&keys[0]));
+            for key in middle {
+                state = _mm_aesenc_si128(state, load(key));
+            }
+
+            _mm_aesenclast_si128(state, load(last))
+        };
+
+        store(block, state);
+    }
+}
+
+// callers must have checked that the CPU supports aes
+#[target_feature(enable = "aes")]
+// the intrinsics only became safe to call here in Rust 1.87
+#[allow(unused_unsafe)]
+unsafe fn decrypt_blocks(keys: &[[u8; BLOCK_LEN]], blocks: &mut [[u8; BLOCK_LEN]]) {
+    let (last, middle) = keys[1..].split_last().unwrap();
+
+    for block in blocks {
+        // SAFETY: aes is enabled for this function and sse2 is baseline
+        let state = unsafe {
+            let mut state = _mm_xor_si128(load(block), load(&keys[0]));
+            for key in middle {
+                state = _mm_aesdec_si128(state, load(key));
+            }
+
+            _mm_aesdeclast_si128(state, load(last))
+        };
+
+        store(block, state);
+    }
+}
@@ -0,0 +1,408 @@
+// Table-free AES. The state of four blocks is held as eight 64-bit planes,
+// bit `16 * block + i` of plane `b` being bit `b` of byte `i` of that block,
+// so SubBytes is a boolean circuit and ShiftRows and MixColumns are bit
+// permutations within each 16-bit lane. Nothing indexes memory by secret data.
+
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use super::consts::*;
+
+const PARALLEL_BLOCKS: usize = 4;
+
+type State = [u64; 8];
+
+// bits of each 16-bit lane holding row r of the state, r = 0..4
+const ROW_MASK: u64 = 0x1111_1111_1111_1111;
+
+#[derive(Clone)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub(crate) struct SoftAes {
+    round_keys: [State; MAX_ROUNDS + 1],
+    rounds: usize,
+}
+
+impl SoftAes {
+    pub(crate) fn new(round_keys: &[[u8; BLOCK_LEN]]) -> Self {
+        let mut sliced = [[0; 8]; MAX_ROUNDS + 1];
+
+        for (dst, key) in sliced.iter_mut().zip(round_keys) {
+            *dst = bitslice(&[*key; PARALLEL_BLOCKS]);
+        }
+
+        Self {
+            round_keys: sliced,
+            rounds: round_keys.len() - 1,
+        }
+    }
+
+    pub(crate) fn encrypt_blocks(&self, blocks: &mut [[u8; BLOCK_LEN]]) {
+        for chunk in blocks.chunks_mut(PARALLEL_BLOCKS) {
+            let mut batch = [[0; BLOCK_LEN]; PARALLEL_BLOCKS];
+            batch[..chunk.len()].copy_from_slice(chunk);
+
+            let mut state = bitslice(&batch);
+            self.encrypt_state(&mut state);
+            unbitslice(&state, &mut batch);
+
+            chunk.copy_from_slice(&batch[..chunk.len()]);
+        }
+    }
+
+    pub(crate) fn decrypt_blocks(&self, blocks: &mut [[u8; BLOCK_LEN]]) {
+        for chunk in blocks.chunks_mut(PARALLEL_BLOCKS) {
+            let mut batch = [[0; BLOCK_LEN]; PARALLEL_BLOCKS];
+            batch[..chunk.len()].copy_from_slice(chunk);
+
+            let mut state = bitslice(&batch);
+            self.decrypt_state(&mut state);
+            unbitslice(&state, &mut batch);
+
+            chunk.copy_from_slice(&batch[..chunk.len()]);
+        }
+    }
+
+    fn encrypt_state(&self, state: &mut State) {
+        add_round_key(state, &self.round_keys[0]);
+
+        for round_key in &self.round_keys[1..self.rounds] {
+            sub_bytes(state);
+            shift_rows(state);
+            mix_columns(state);
+            add_round_key(state, round_key);
+        }
+
+        sub_bytes(state);
+        shift_rows(state);
+        add_round_key(state, &self.round_keys[self.rounds]);
+    }
+
+    fn decrypt_state(&self, state: &mut State) {
+        add_round_key(state, &self.round_keys[self.rounds]);
+
+        for round_key in self.round_keys[1..self.rounds].iter().rev() {
+            inv_shift_rows(state);
+            inv_sub_bytes(state);
+            add_round_key(state, round_key);
+            inv_mix_columns(state);
+        }
+
+        inv_shift_rows(state);
+        inv_sub_bytes(state);
+        add_round_key(state, &self.round_keys[0]);
+    }
+}
+
+// SubWord of the key expansion, on the four bytes of a little-endian word
+pub(crate) fn sub_word(word: u32) -> u32 {
+    let mut block = [0; BLOCK_LEN];
+    block[..4].copy_from_slice(&word.to_le_bytes());
+
+    let mut batch = [block; PARALLEL_BLOCKS];
+    let mut state = bitslice(&batch);
+    sub_bytes(&mut state);
+    unbitslice(&state, &mut batch);
+
+    u32::from_le_bytes(batch[0][..4].try_into().unwrap())
+}
+
+// transposes an 8x8 bit matrix, byte i of the result holds bit i of every
+// input byte
+#[inline(always)]
+fn transpose8(mut x: u64) -> u64 {
+    let t = (x ^ (x >> 7)) & 0x00aa_00aa_00aa_00aa;
+    x ^= t ^ (t << 7);
+    let t = (x ^ (x >> 14)) & 0x0000_cccc_0000_cccc;
+    x ^= t ^ (t << 14);
+    let t = (x ^ (x >> 28)) & 0x0000_0000_f0f0_f0f0;
+    x ^= t ^ (t << 28);
+
+    x
+}
+
+fn bitslice(blocks: &[[u8; BLOCK_LEN]; PARALLEL_BLOCKS]) -> State {
+    let mut state = [0; 8];
+
+    for (chunk, half) in blocks.iter().flat_map(|b| b.chunks_exact(8)).enumerate() {
+        let t = transpose8(u64::from_le_bytes(half.try_into().unwrap()));
+
+        for (bit, plane) in state.iter_mut().enumerate() {
+            *plane |= ((t >> (8 * bit)) & 0xff) << (8 * chunk);
+        }
+    }
+
+    state
+}
+
+fn unbitslice(state: &State, blocks: &mut [[u8; BLOCK_LEN]; PARALLEL_BLOCKS]) {
+    for (chunk, half) in blocks
+        .iter_mut()
+        .flat_map(|b| b.chunks_exact_mut(8))
+        .enumerate()
+    {
+        let mut t = 0;
+        for (bit, plane) in state.iter().enumerate() {
+            t |= ((plane >> (8 * chunk)) & 0xff) << (8 * bit);
+        }
+
+        half.copy_from_slice(&transpose8(t).to_le_bytes());
+    }
+}
+
+#[inline(always)]
+fn add_round_key(state: &mut State, round_key: &State) {
+    for (plane, key) in state.iter_mut().zip(round_key) {
+        *plane ^= key;
+    }
+}
+
+// rotates every 16-bit lane right by n bits
+#[inline(always)]
+fn rotate_lanes(x: u64, n: u32) -> u64 {
+    let low = 0xffff_u64 >> n;
+    let low = low * 0x0001_0001_0001_0001;
+
+    ((x >> n) & low) | ((x << (16 - n)) & !low)
+}
+
+// rotates every 4-bit column right by n rows
+#[inline(always)]
+fn rotate_columns(x: u64, n: u32) -> u64 {
+    let low = (0xf_u64 >> n) * 0x1111_1111_1111_1111;
+
+    ((x >> n) & low) | ((x << (4 - n)) & !low)
+}
+
+// byte (r, c) takes the byte at (r, c + r)
+#[inline(always)]
+fn shift_rows(state: &mut State) {
+    for plane in state.iter_mut() {
+        let x = *plane;
+        *plane = (x & ROW_MASK)
+            | (rotate_lanes(x, 4) & (ROW_MASK << 1))
+            | (rotate_lanes(x, 8) & (ROW_MASK << 2))
+            | (rotate_lanes(x, 12) & (ROW_MASK << 3));
+    }
+}
+
+#[inline(always)]
+fn inv_shift_rows(state: &mut State) {
+    for plane in state.iter_mut() {
+        let x = *plane;
+        *plane = (x & ROW_MASK)
+            | (rotate_lanes(x, 12) & (ROW_MASK << 1))
+            | (rotate_lanes(x, 8) & (ROW_MASK << 2))
+            | (rotate_lanes(x, 4) & (ROW_MASK << 3));
+    }
+}
+
+// multiplication by x in GF(2^8) modulo x^8 + x^4 + x^3 + x + 1
+#[inline(always)]
+fn xtime(a: &State) -> State {
+    [
+        a[7],
+        a[0] ^ a[7],
+        a[1],
+        a[2] ^ a[7],
+        a[3] ^ a[7],
+        a[4],
+        a[5],
+        a[6],
+    ]
+}
+
+// b_r = 2 * a_r + 3 * a_(r+1) + a_(r+2) + a_(r+3)
+//     = 2 * (a_r + a_(r+1)) + a_(r+1) + a_(r+2) + a_(r+3)
+fn mix_columns(state: &mut State) {
+    let a1 = state.map(|x| rotate_columns(x, 1));
+    let a2 = state.map(|x| rotate_columns(x, 2));
+    let a3 = state.map(|x| rotate_columns(x, 3));
+
+    let mut sum = *state;
+    for (s, a) in sum.iter_mut().zip(&a1) {
+        *s ^= a;
+    }
+
+    let doubled = xtime(&sum);
+    for i in 0..8 {
+        state[i] = doubled[i] ^ a1[i] ^ a2[i] ^ a3[i];
+    }
+}
+
+// the inverse matrix factors as MixColumns times {04, 00, 05, 00}:
+// a_r += 4 * (a_r + a_(r+2)) followed by a forward MixColumns
+fn inv_mix_columns(state: &mut State) {
+    let a2 = state.map(|x| rotate_columns(x, 2));
+
+    let mut sum = *state;
+    for (s, a) in sum.iter_mut().zip(&a2) {
+        *s ^= a;
+    }
+
+    let quadrupled = xtime(&xtime(&sum));
+    for (s, q) in state.iter_mut().zip(&quadrupled) {
+        *s ^= q;
+    }
+
+    mix_columns(state);
+}
+
+// the Boyar-Peralta circuit for the AES S-box, 113 gates
+fn sub_bytes(q: &mut State) {
+    let x0 = q[7];
+    let x1 = q[6];
+    let x2 = q[5];
+    let x3 = q[4];
+    let x4 = q[3];
+    let x5 = q[2];
+    let x6 = q[1];
+    let x7 = q[0];
+
+    // top linear transformation
+    let y14 = x3 ^ x5;
+    let y13 = x0 ^ x6;
+    let y9 = x0 ^ x3;
+    let y8 = x0 ^ x5;
+    let t0 = x1 ^ x2;
+    let y1 = t0 ^ x7;
+    let y4 = y1 ^ x3;
+    let y12 = y13 ^ y14;
+    let y2 = y1 ^ x0;
+    let y5 = y1 ^ x6;
+    let y3 = y5 ^ y8;
+    let t1 = x4 ^ y12;
+    let y15 = t1 ^ x5;
+    let y20 = t1 ^ x1;
+    let y6 = y15 ^ x7;
+    let y10 = y15 ^ t0;
+    let y11 = y20 ^ y9;
+    let y7 = x7 ^ y11;
+    let y17 = y10 ^ y11;
+    let y19 = y10 ^ y8;
+    let y16 = t0 ^ y11;
+    let y21 = y13 ^ y16;
+    let y18 = x0 ^ y16;
+
+    // non-linear section, inversion in GF(2^8) through GF(2^4)
+    let t2 = y12 & y15;
+    let t3 = y3 & y6;
+    let t4 = t3 ^ t2;
+    let t5 = y4 & x7;
+    let t6 = t5 ^ t2;
+    let t7 = y13 & y16;
+    let t8 = y5 & y1;
+    let t9 = t8 ^ t7;
+    let t10 = y2 & y7;
+    let t11 = t10 ^ t7;
+    let t12 = y9 & y11;
+    let t13 = y14 & y17;
+    let t14 = t13 ^ t12;
+    let t15 = y8 & y10;
+    let t16 = t15 ^ t12;
+    let t17 = t4 ^ t14;
+    let t18 = t6 ^ t16;
+    let t19 = t9 ^ t14;
+    let t20 = t11 ^ t16;
+    let t21 = t17 ^ y20;
+    let t22 = t18 ^ y19;
+    let t23 = t19 ^ y21;
+    let t24 = t20 ^ y18;
+
+    let t25 = t21 ^ t22;
+    let t26 = t21 & t23;
+    let t27 = t24 ^ t26;
+    let t28 = t25 & t27;
+    let t29 = t28 ^ t22;
+    let t30 = t23 ^ t24;
+    let t31 = t22 ^ t26;
+    let t32 = t31 & t30;
+    let t33 = t32 ^ t24;
+    let t34 = t23 ^ t33;
+    let t35 = t27 ^ t33;
+    let t36 = t24 & t35;
+    let t37 = t36 ^ t34;
+    let t38 = t27 ^ t36;
+    let t39 = t29 & t38;
+    let t40 = t25 ^ t39;
+
+    let t41 = t40 ^ t37;
+    let t42 = t29 ^ t33;
+    let t43 = t29 ^ t40;
+    let t44 = t33 ^ t37;
+    let t45 = t42 ^ t41;
+    let z0 = t44 & y15;
+    let z1 = t37 & y6;
+    let z2 = t33 & x7;
+    let z3 = t43 & y16;
+    let z4 = t40 & y1;
+    let z5 = t29 & y7;
+    let z6 = t42 & y11;
+    let z7 = t45 & y17;
+    let z8 = t41 & y10;
+    let z9 = t44 & y12;
+    let z10 = t37 & y3;
+    let z11 = t33 & y4;
+    let z12 = t43 & y13;
+    let z13 = t40 & y5;
+    let z14 = t29 & y2;
+    let z15 = t42 & y9;
+    let z16 = t45 & y14;
+    let z17 = t41 & y8;
+
+    // bottom linear transformation
+    let t46 = z15 ^ z16;
+    let t47 = z10 ^ z11;
+    let t48 = z5 ^ z13;
+    let t49 = z9 ^ z10;
+    let t50 = z2 ^ z12;
+    let t51 = z2 ^ z5;
+    let t52 = z7 ^ z8;
+    let t53 = z0 ^ z3;
+    let t54 = z6 ^ z7;
+    let t55 = z16 ^ z17;
+    let t56 = z12 ^ t48;
+    let t57 = t50 ^ t53;
+    let t58 = z4 ^ t46;
+    let t59 = z3 ^ t54;
+    let t60 = t46 ^ t57;
+    let t61 = z14 ^ t57;
+    let t62 = t52 ^ t58;
+    let t63 = t49 ^ t58;
+    let t64 = z4 ^ t59;
+    let t65 = t61 ^ t62;
+    let t66 = z1 ^ t63;
+    let s0 = t59 ^ t63;
+    let s6 = t56 ^ !t62;
+    let s7 = t48 ^ !t60;
+    let t67 = t64 ^ t65;
+    let s3 = t53 ^ t66;
+    let s4 = t51 ^ t66;
+    let s5 = t47 ^ t65;
+    let s1 = t64 ^ !s3;
+    let s2 = t55 ^ !t67;
+
+    *q = [s7, s6, s5, s4, s3, s2, s1, s0];
+}
+
+// the inverse affine transformation x -> A^-1 * x + 0x05, which is
+// A^-1 * (x + 0x63)
+#[inline(always)]
+fn inv_affine(q: &mut State) {
+    let mut out = [0; 8];
+    for (i, o) in out.iter_mut().enumerate() {
+        *o = q[(i + 2) % 8] ^ q[(i + 5) % 8] ^ q[(i + 7) % 8];
+    }
+
+    out[0] = !out[0];
+    out[2] = !out[2];
+
+    *q = out;
+}
+
+// S^-1 = inv_affine o S o inv_affine, since S is the inversion followed by
+// the affine map
+fn inv_sub_bytes(q: &mut State) {
+    inv_affine(q);
+    sub_bytes(q);
+    inv_affine(q);
+}
@@ -0,0 +1,23 @@
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use super::consts::*;
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+#[repr(transparent)]
+pub struct Key128([u8; KEY128_LEN]);
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+#[repr(transparent)]
+pub struct Key192([u8; KEY192_LEN]);
+
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+#[repr(transparent)]
+pub struct Key256([u8; KEY256_LEN]);
+
+bytes_wrapper_impl!(Key128, KEY128_LEN, secret, random);
+bytes_wrapper_impl!(Key192, KEY192_LEN, secret, random);
+bytes_wrapper_impl!(Key256, KEY256_LEN, secret, random);
@@ -0,0 +1,235 @@
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+pub use self::types::Nonce;
+pub use crate::aead::{Aead, Error, TAG_LEN, Tag};
+
+use self::ghash::GHash;
+use crate::aes::{Aes128, Aes256, BLOCK_LEN, BlockCipher, Key128, Key256};
+use crate::utils::xor_keystream;
+
+mod ghash;
+mod types;
+
+pub const NONCE_LEN: usize = 12;
+
+// NIST SP 800-38D section 5.2.1.1: 2^39 - 256 bits of plaintext, 2^64 - 1
+// bits of associated data and IV
+pub const MAX_PLAINTEXT_LEN: u64 = (1 << 36) - 32;
+pub const MAX_AAD_LEN: u64 = (1 << 61) - 1;
+pub const MAX_IV_LEN: u64 = (1 << 61) - 1;
+
+// counter blocks encrypted per call to the block cipher
+const BATCH_BLOCKS: usize = 8;
+
+pub type Aes128Gcm = AesGcm<Aes128>;
+pub type Aes256Gcm = AesGcm<Aes256>;
+
+// AES-GCM per NIST SP 800-38D with 128-bit tags. The block cipher and the
+// hash subkey both zeroize on drop under the `zeroize` feature.
+#[derive(Clone)]
+pub struct AesGcm<C: BlockCipher> {
+    cipher: C,
+    ghash: GHash,
+}
+
+impl<C: BlockCipher> AesGcm<C> {
+    // picks the AES-NI and PCLMULQDQ backends when the CPU has them
+    pub fn new(key: &C::Key) -> Self {
+        Self::from_cipher(C::new(key), true)
+    }
+
+    fn from_cipher(cipher: C, detect: bool) -> Self {
+        let mut h = [0; BLOCK_LEN];
+        cipher.encrypt_block(&mut h);
+
+        let ghash = GHash::new(&h, detect);
+
+        #[cfg(feature = "zeroize")]
+        h.zeroize();
+
+        Self { cipher, ghash }
+    }
+
+    #[inline]
+    pub fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        aad: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        self.encrypt_in_place_detached_with_iv(nonce.bytes(), aad, buffer)
+    }
+
+    #[inline]
+    pub fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        aad: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        self.decrypt_in_place_detached_with_iv(nonce.bytes(), aad, buffer, tag)
+    }
+
+    // IVs other than 96 bits are hashed into the initial counter block.
+    // They are only as good as a random 96-bit nonce at best, prefer `Nonce`.
+    pub fn encrypt_in_place_detached_with_iv(
+        &self,
+        iv: &[u8],
+        aad: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        check_lengths(aad, buffer)?;
+        let j0 = self.initial_counter_block(iv)?;
+
+        self.apply_keystream(&j0, buffer);
+
+        Ok(self.compute_tag(&j0, aad, buffer))
+    }
+
+    // the buffer is left untouched when authentication fails
+    pub fn decrypt_in_place_detached_with_iv(
+        &self,
+        iv: &[u8],
+        aad: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        check_lengths(aad, buffer)?;
+        let j0 = self.initial_counter_block(iv)?;
+
+        if self.compute_tag(&j0, aad, buffer) != *tag {
+            return Err(Error::InvalidTag);
+        }
+
+        self.apply_keystream(&j0, buffer);
+
+        Ok(())
+    }
+
+    // J0 from SP 800-38D section 7.1 step 2
+    fn initial_counter_block(&self, iv: &[u8]) -> Result<[u8; BLOCK_LEN], Error> {
+        if iv.is_empty() || iv.len() as u64 > MAX_IV_LEN {
+            return Err(Error::InvalidIvLength);
+        }
+
+        let mut j0 = [0; BLOCK_LEN];
+
+        match iv.len() == NONCE_LEN {
+            true => {
+                j0[..NONCE_LEN].copy_from_slice(iv);
+                j0[BLOCK_LEN - 1] = 1;
+            }
+            false => {
+                let mut lengths = [0; BLOCK_LEN];
+                lengths[8..].copy_from_slice(&(iv.len() as u64 * 8).to_be_bytes());
+
+                let mut ghash = self.ghash.clone();
+                ghash.update_padded(iv);
+                ghash.update_padded(&lengths);
+                j0 = ghash.finalize();
+            }
+        }
+
+        Ok(j0)
+    }
+
+    // GCTR starting at inc32(J0), the counter wraps within its low 32 bits
+    fn apply_keystream(&self, j0: &[u8; BLOCK_LEN], buffer: &mut [u8]) {
+        let mut counter = u32::from_be_bytes(j0[12..].try_into().unwrap());
+
+        for chunk in buffer.chunks_mut(BATCH_BLOCKS * BLOCK_LEN) {
+            let mut blocks = [*j0; BATCH_BLOCKS];
+            let count = chunk.len().div_ceil(BLOCK_LEN);
+
+            for block in &mut blocks[..count] {
+                counter = counter.wrapping_add(1);
+                block[12..].copy_from_slice(&counter.to_be_bytes());
+            }
+
+            self.cipher.encrypt_blocks(&mut blocks[..count]);
+            xor_keystream(blocks.as_flattened(), chunk);
+        }
+    }
+
+    fn compute_tag(&self, j0: &[u8; BLOCK_LEN], aad: &[u8], ciphertext: &[u8]) -> Tag {
+        let mut lengths = [0; BLOCK_LEN];
+        lengths[..8].copy_from_slice(&(aad.len() as u64 * 8).to_be_bytes());
+        lengths[8..].copy_from_slice(&(ciphertext.len() as u64 * 8).to_be_bytes());
+
+        let mut ghash = self.ghash.clone();
+        ghash.update_padded(aad);
+        ghash.update_padded(ciphertext);
+        ghash.update_padded(&lengths);
+        let mut tag = ghash.finalize();
+
+        let mut mask = *j0;
+        self.cipher.encrypt_block(&mut mask);
+        xor_keystream(&mask, &mut tag);
+
+        Tag::new(tag)
+    }
+}
+
+impl<C: BlockCipher> Aead for AesGcm<C> {
+    const KEY_LEN: usize = C::KEY_LEN;
+    const NONCE_LEN: usize = NONCE_LEN;
+    const TAG_LEN: usize = TAG_LEN;
+
+    type Key = C::Key;
+    type Nonce = Nonce;
+    type Tag = Tag;
+
+    #[inline]
+    fn new(key: &C::Key) -> Self {
+        Self::new(key)
+    }
+
+    #[inline]
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        aad: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        self.encrypt_in_place_detached(nonce, aad, buffer)
+    }
+
+    #[inline]
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        aad: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        self.decrypt_in_place_detached(nonce, aad, buffer, tag)
+    }
+}
+
+// the software AES and GHASH backends, constant-time on every target
+impl AesGcm<Aes128> {
+    pub fn new_soft(key: &Key128) -> Self {
+        Self::from_cipher(Aes128::new_soft(key), false)
+    }
+}
+
+impl AesGcm<Aes256> {
+    pub fn new_soft(key: &Key256) -> Self {
+        Self::from_cipher(Aes256::new_soft(key), false)
+    }
+}
+
+#[inline(always)]
+fn check_lengths(aad: &[u8], buffer: &[u8]) -> Result<(), Error> {
+    if buffer.len() as u64 > MAX_PLAINTEXT_LEN {
+        return Err(Error::PlaintextTooLong);
+    }
+
+    if aad.len() as u64 > MAX_AAD_LEN {
+        return Err(Error::AadTooLong);
+    }
+
+    Ok(())
+}
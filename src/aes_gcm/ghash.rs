@@ -0,0 +1,37 @@
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use crate::polyval::{BLOCK_LEN, Polyval};
+
+// GHASH through POLYVAL, RFC 8452 appendix A:
+// GHASH(H, X) = rev(POLYVAL(mulX(rev(H)), rev(X_1), ..., rev(X_n)))
+#[derive(Clone)]
+pub(crate) struct GHash(Polyval);
+
+impl GHash {
+    pub(crate) fn new(h: &[u8; BLOCK_LEN], detect: bool) -> Self {
+        let mut h = *h;
+        h.reverse();
+
+        // multiplication by x in the POLYVAL field
+        let v = u128::from_le_bytes(h);
+        let carry = v >> 127;
+        let v = (v << 1) ^ carry ^ (carry << 127) ^ (carry << 126) ^ (carry << 121);
+
+        #[cfg(feature = "zeroize")]
+        h.zeroize();
+
+        Self(Polyval::new(&v.to_le_bytes(), detect))
+    }
+
+    pub(crate) fn update_padded(&mut self, data: &[u8]) {
+        self.0.update_padded_with(data, |block| block.reverse());
+    }
+
+    pub(crate) fn finalize(self) -> [u8; BLOCK_LEN] {
+        let mut out = self.0.finalize();
+        out.reverse();
+
+        out
+    }
+}
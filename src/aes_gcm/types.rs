@@ -0,0 +1,7 @@
+use super::NONCE_LEN;
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Nonce([u8; NONCE_LEN]);
+
+bytes_wrapper_impl!(Nonce, NONCE_LEN, public, random);
@@ -0,0 +1,241 @@
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+pub use crate::aead::{Aead, Error, TAG_LEN, Tag};
+pub use crate::aes_gcm::Nonce;
+
+use crate::aes::{Aes128, Aes256, BLOCK_LEN, BlockCipher, Key128, Key256};
+use crate::polyval::Polyval;
+use crate::utils::xor_keystream;
+
+pub const NONCE_LEN: usize = 12;
+
+// RFC 8452 section 6: plaintext and associated data up to 2^36 bytes
+pub const MAX_PLAINTEXT_LEN: u64 = 1 << 36;
+pub const MAX_AAD_LEN: u64 = 1 << 36;
+
+// counter blocks encrypted per call to the block cipher
+const BATCH_BLOCKS: usize = 8;
+
+pub type Aes128GcmSiv = AesGcmSiv<Aes128>;
+pub type Aes256GcmSiv = AesGcmSiv<Aes256>;
+
+// AES-GCM-SIV per RFC 8452. Every nonce derives its own authentication and
+// encryption keys, and the tag doubles as the synthetic IV, so repeating a
+// nonce only reveals whether the same message was sent twice.
+#[derive(Clone)]
+pub struct AesGcmSiv<C: BlockCipher> {
+    // the key-generating key
+    cipher: C,
+    // builds the per-message cipher with the same backend choice
+    new_cipher: fn(&C::Key) -> C,
+    detect: bool,
+}
+
+impl<C> AesGcmSiv<C>
+where
+    C: BlockCipher,
+    C::Key: Default + AsMut<[u8]>,
+{
+    // picks the AES-NI and PCLMULQDQ backends when the CPU has them
+    pub fn new(key: &C::Key) -> Self {
+        Self {
+            cipher: C::new(key),
+            new_cipher: C::new,
+            detect: true,
+        }
+    }
+
+    pub fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        aad: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        check_lengths(aad, buffer)?;
+        let (polyval, cipher) = self.derive_keys(nonce);
+
+        let tag = compute_tag(&cipher, polyval, nonce, aad, buffer);
+        apply_keystream(&cipher, &tag, buffer);
+
+        Ok(Tag::new(tag))
+    }
+
+    // the buffer is left untouched when authentication fails
+    pub fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        aad: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        check_lengths(aad, buffer)?;
+        let (polyval, cipher) = self.derive_keys(nonce);
+
+        // the tag covers the plaintext, so it is decrypted first and
+        // encrypted back on failure
+        apply_keystream(&cipher, tag.bytes(), buffer);
+
+        if Tag::new(compute_tag(&cipher, polyval, nonce, aad, buffer)) != *tag {
+            apply_keystream(&cipher, tag.bytes(), buffer);
+            return Err(Error::InvalidTag);
+        }
+
+        Ok(())
+    }
+
+    // RFC 8452 section 4: the first half of AES(K, LE32(i) || nonce) for
+    // i = 0, 1 is the authentication key, the following blocks make up the
+    // encryption key
+    fn derive_keys(&self, nonce: &Nonce) -> (Polyval, C) {
+        let halves = 2 + C::KEY_LEN / 8;
+
+        let mut blocks = [[0; BLOCK_LEN]; 6];
+        for (i, block) in blocks[..halves].iter_mut().enumerate() {
+            block[..4].copy_from_slice(&(i as u32).to_le_bytes());
+            block[4..].copy_from_slice(nonce.bytes());
+        }
+        self.cipher.encrypt_blocks(&mut blocks[..halves]);
+
+        let mut auth_key = [0; BLOCK_LEN];
+        auth_key[..8].copy_from_slice(&blocks[0][..8]);
+        auth_key[8..].copy_from_slice(&blocks[1][..8]);
+
+        let mut enc_key = C::Key::default();
+        for (chunk, block) in enc_key.as_mut().chunks_exact_mut(8).zip(&blocks[2..]) {
+            chunk.copy_from_slice(&block[..8]);
+        }
+
+        let polyval = Polyval::new(&auth_key, self.detect);
+        let cipher = (self.new_cipher)(&enc_key);
+
+        #[cfg(feature = "zeroize")]
+        {
+            blocks.zeroize();
+            auth_key.zeroize();
+        }
+
+        (polyval, cipher)
+    }
+}
+
+impl<C> Aead for AesGcmSiv<C>
+where
+    C: BlockCipher,
+    C::Key: Default + AsMut<[u8]>,
+{
+    const KEY_LEN: usize = C::KEY_LEN;
+    const NONCE_LEN: usize = NONCE_LEN;
+    const TAG_LEN: usize = TAG_LEN;
+
+    type Key = C::Key;
+    type Nonce = Nonce;
+    type Tag = Tag;
+
+    #[inline]
+    fn new(key: &C::Key) -> Self {
+        Self::new(key)
+    }
+
+    #[inline]
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        aad: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag, Error> {
+        self.encrypt_in_place_detached(nonce, aad, buffer)
+    }
+
+    #[inline]
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce,
+        aad: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag,
+    ) -> Result<(), Error> {
+        self.decrypt_in_place_detached(nonce, aad, buffer, tag)
+    }
+}
+
+// the software AES and POLYVAL backends, constant-time on every target
+impl AesGcmSiv<Aes128> {
+    pub fn new_soft(key: &Key128) -> Self {
+        Self {
+            cipher: Aes128::new_soft(key),
+            new_cipher: Aes128::new_soft,
+            detect: false,
+        }
+    }
+}
+
+impl AesGcmSiv<Aes256> {
+    pub fn new_soft(key: &Key256) -> Self {
+        Self {
+            cipher: Aes256::new_soft(key),
+            new_cipher: Aes256::new_soft,
+            detect: false,
+        }
+    }
+}
+
+#[inline(always)]
+fn check_lengths(aad: &[u8], buffer: &[u8]) -> Result<(), Error> {
+    if buffer.len() as u64 > MAX_PLAINTEXT_LEN {
+        return Err(Error::PlaintextTooLong);
+    }
+
+    if aad.len() as u64 > MAX_AAD_LEN {
+        return Err(Error::AadTooLong);
+    }
+
+    Ok(())
+}
+
+// RFC 8452 section 4: POLYVAL over the padded inputs and their bit lengths,
+// xored with the nonce and encrypted with the top bit cleared
+fn compute_tag<C: BlockCipher>(
+    cipher: &C,
+    mut polyval: Polyval,
+    nonce: &Nonce,
+    aad: &[u8],
+    plaintext: &[u8],
+) -> [u8; TAG_LEN] {
+    let mut lengths = [0; BLOCK_LEN];
+    lengths[..8].copy_from_slice(&(aad.len() as u64 * 8).to_le_bytes());
+    lengths[8..].copy_from_slice(&(plaintext.len() as u64 * 8).to_le_bytes());
+
+    polyval.update_padded(aad);
+    polyval.update_padded(plaintext);
+    polyval.update_padded(&lengths);
+    let mut tag = polyval.finalize();
+
+    xor_keystream(nonce.bytes(), &mut tag);
+    tag[15] &= 0x7f;
+    cipher.encrypt_block(&mut tag);
+
+    tag
+}
+
+// CTR from the tag with its top bit set, the counter is the first 32 bits
+// read as a little-endian integer and wraps without carrying
+fn apply_keystream<C: BlockCipher>(cipher: &C, tag: &[u8; TAG_LEN], buffer: &mut [u8]) {
+    let mut initial = *tag;
+    initial[15] |= 0x80;
+
+    let mut counter = u32::from_le_bytes(initial[..4].try_into().unwrap());
+
+    for chunk in buffer.chunks_mut(BATCH_BLOCKS * BLOCK_LEN) {
+        let mut blocks = [initial; BATCH_BLOCKS];
+        let count = chunk.len().div_ceil(BLOCK_LEN);
+
+        for block in &mut blocks[..count] {
+            block[..4].copy_from_slice(&counter.to_le_bytes());
+            counter = counter.wrapping_add(1);
+        }
+
+        cipher.encrypt_blocks(&mut blocks[..count]);
+        xor_keystream(blocks.as_flattened(), chunk);
+    }
+}
@@ -5,11 +5,28 @@ use self::consts::*;
 pub use self::djb::{DjbChaCha8Rng, DjbChaCha12Rng, DjbChaCha20Rng, DjbChaChaRng};
 pub use self::ietf::{ChaCha, ChaCha8, ChaCha12, ChaCha20};
 pub use self::ietf::{ChaCha8Rng, ChaCha12Rng, ChaCha20Rng, ChaChaRng};
+pub use self::ietf::{
+    FastKeyErasureChaCha8Rng, FastKeyErasureChaCha12Rng, FastKeyErasureChaCha20Rng,
+    FastKeyErasureRng,
+};
+pub use self::reseeding::{ClockSource, DEFAULT_RESEED_BYTES, EntropySource, PidSource};
+pub use self::reseeding::{
+    ReseedingChaCha8Rng, ReseedingChaCha12Rng, ReseedingChaCha20Rng, ReseedingChaChaRng,
+};
+pub use self::sample::{SampleRange, UniformInt};
+pub use self::thread::{ThreadRng, thread_rng};
+#[cfg(feature = "rand")]
+pub use self::types::Results;
 pub use self::types::{Constants, Key, Seed};
+pub use self::xchacha::{XChaCha, XChaCha8, XChaCha12, XChaCha20, XNonce};
 
 pub mod djb; // DJB original: 64-bit counter, 64-bit nonce
 pub mod ietf; // RFC 8439 version: 32-bit counter, 96-bit nonce
+mod reseeding; // reseeds by output, time and process id
+mod sample; // ranges, floats and slices without the rand crate
+mod thread; // a lazily seeded generator per thread
 mod types;
+pub mod xchacha; // 192-bit nonce through an HChaCha subkey
 
 pub(crate) mod consts;
 
@@ -8,6 +8,13 @@ pub const OUTPUT_LEN: usize = 64;
 pub const CONSTANTS_LEN_WORDS: usize = CONSTANTS_LEN / 4;
 pub const KEY_LEN_WORDS: usize = KEY_LEN / 4;
 pub const STATE_LEN_WORDS: usize = STATE_LEN / 4;
+pub const OUTPUT_LEN_WORDS: usize = OUTPUT_LEN / 4;
+
+// blocks generated per call through rand_core's BlockRngCore
+#[cfg(feature = "rand")]
+pub const RESULTS_BLOCKS: usize = 4;
+#[cfg(feature = "rand")]
+pub const RESULTS_LEN_WORDS: usize = RESULTS_BLOCKS * OUTPUT_LEN_WORDS;
 
 pub const CONSTANTS_RANGE: Range<usize> = 0..4;
 pub const KEY_RANGE: Range<usize> = 4..12;
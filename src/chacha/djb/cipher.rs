@@ -1,6 +1,7 @@
 #[cfg(feature = "zeroize")]
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
+use crate::Error;
 use crate::chacha::consts::OUTPUT_LEN;
 use crate::chacha::{Constants, Key};
 use crate::utils::xor_keystream;
@@ -29,6 +30,11 @@ impl<const ROUNDS: usize> DjbChaCha<ROUNDS> {
         }
     }
 
+    // checks the lengths of untrusted key and nonce bytes
+    pub fn new_from_slices(key: &[u8], nonce: &[u8]) -> Result<Self, Error> {
+        Ok(Self::new(&Key::try_from(key)?, &Nonce::try_from(nonce)?))
+    }
+
     #[inline]
     pub fn get_constants(&self) -> &Constants {
         Constants::from_words_ref(self.core.get_constants())
@@ -1,8 +1,15 @@
+#[cfg(feature = "rand")]
+use rand_core::SeedableRng;
+#[cfg(feature = "rand")]
+use rand_core::block::{BlockRngCore, CryptoBlockRng};
+
 #[cfg(feature = "zeroize")]
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
 use super::{COUNTER_LEN, COUNTER_RANGE, NONCE_LEN, NONCE_RANGE};
 use crate::chacha::consts::*;
+#[cfg(feature = "rand")]
+use crate::chacha::{Results, Seed};
 use crate::chacha::{column_round, diagonal_round};
 use crate::utils::{bytes_to_words, words_to_bytes};
 
@@ -79,19 +86,20 @@ impl<const ROUNDS: usize> DjbChaChaCore<ROUNDS> {
         bytes_to_words(&bytes, &mut self.0[NONCE_RANGE]);
     }
 
+    // one keystream block as native words, the counter advances by one
     #[inline(always)]
-    pub fn generate_block(&mut self, dst: &mut [u8]) {
-        let mut working = self.0;
+    pub fn generate_block_words(&mut self, dst: &mut [u32; STATE_LEN_WORDS]) {
+        *dst = self.0;
 
         for i in 0..ROUNDS {
             match i % 2 == 0 {
-                true => column_round(&mut working),
-                false => diagonal_round(&mut working),
+                true => column_round(dst),
+                false => diagonal_round(dst),
             }
         }
 
         (0..STATE_LEN_WORDS).for_each(|i| {
-            working[i] = working[i].wrapping_add(self.0[i]);
+            dst[i] = dst[i].wrapping_add(self.0[i]);
         });
 
         const COUNTER_L: usize = COUNTER_RANGE.start;
@@ -102,6 +110,12 @@ impl<const ROUNDS: usize> DjbChaChaCore<ROUNDS> {
         if self.0[COUNTER_L] == 0 {
             self.0[COUNTER_H] = self.0[COUNTER_H].wrapping_add(1)
         }
+    }
+
+    #[inline(always)]
+    pub fn generate_block(&mut self, dst: &mut [u8]) {
+        let mut working = [0; STATE_LEN_WORDS];
+        self.generate_block_words(&mut working);
 
         words_to_bytes(&working, dst);
 
@@ -109,3 +123,29 @@ impl<const ROUNDS: usize> DjbChaChaCore<ROUNDS> {
         working.zeroize();
     }
 }
+
+// lets rand_core's `BlockRng` and rand's `ReseedingRng` drive the core, a
+// call fills `RESULTS_BLOCKS` consecutive blocks
+#[cfg(feature = "rand")]
+impl<const ROUNDS: usize> BlockRngCore for DjbChaChaCore<ROUNDS> {
+    type Item = u32;
+    type Results = Results;
+
+    fn generate(&mut self, results: &mut Results) {
+        for block in results.blocks_mut() {
+            self.generate_block_words(block);
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<const ROUNDS: usize> CryptoBlockRng for DjbChaChaCore<ROUNDS> {}
+
+#[cfg(feature = "rand")]
+impl<const ROUNDS: usize> SeedableRng for DjbChaChaCore<ROUNDS> {
+    type Seed = Seed;
+
+    fn from_seed(seed: Seed) -> Self {
+        Self::new(seed.bytes(), 0)
+    }
+}
@@ -1,28 +1,36 @@
+use std::io;
+
 #[cfg(feature = "rand")]
 use rand_core::{CryptoRng, RngCore, SeedableRng};
 
 use super::core::DjbChaChaCore;
+use crate::chacha::sample::{self, SampleRange, UniformInt};
 use crate::chacha::types::Seed;
 use crate::chacha::{Constants, consts::*};
+use crate::{Error, os};
 
 const DEFAULT_STREAM_ID: u64 = 0;
 
+// a 64-bit block counter of 16-word blocks
+const WORD_POS_MASK: u128 = (1 << 68) - 1;
+
 pub struct DjbChaChaRng<const ROUNDS: usize> {
     core: DjbChaChaCore<ROUNDS>,
-    buffer: [u8; OUTPUT_LEN],
-    buffer_pos: usize,
+    // one block of keystream words, `index` is the next unused one
+    buffer: [u32; OUTPUT_LEN_WORDS],
+    index: usize,
 }
 
 impl<const ROUNDS: usize> DjbChaChaRng<ROUNDS> {
     pub fn new(seed: &Seed, stream_id: u64) -> Self {
-        let buffer = [0; OUTPUT_LEN];
+        let buffer = [0; OUTPUT_LEN_WORDS];
         let core = DjbChaChaCore::new(seed.bytes(), stream_id);
-        let buffer_pos = buffer.len();
+        let index = buffer.len();
 
         Self {
             core,
             buffer,
-            buffer_pos,
+            index,
         }
     }
 
@@ -31,6 +39,17 @@ impl<const ROUNDS: usize> DjbChaChaRng<ROUNDS> {
         Self::new(seed, DEFAULT_STREAM_ID)
     }
 
+    // a fresh seed from the OS, see `os::fill`
+    pub fn from_entropy() -> Result<Self, os::Error> {
+        Ok(Self::from_seed(&Seed::from_os()?))
+    }
+
+    // checks the length of untrusted seed bytes, the stream id is a plain
+    // integer here
+    pub fn new_from_slices(seed: &[u8], stream_id: u64) -> Result<Self, Error> {
+        Ok(Self::new(&Seed::try_from(seed)?, stream_id))
+    }
+
     #[inline]
     pub fn get_core(&self) -> &DjbChaChaCore<ROUNDS> {
         &self.core
@@ -97,9 +116,14 @@ impl<const ROUNDS: usize> DjbChaChaRng<ROUNDS> {
         self.core.get_nonce()
     }
 
-    #[inline]
+    // words still buffered are regenerated from the new stream at the same
+    // position, as rand_chacha's `set_stream` does
     pub fn set_stream_id(&mut self, stream_id: u64) {
         self.core.set_nonce(stream_id);
+
+        if self.index < OUTPUT_LEN_WORDS {
+            self.set_word_pos(self.get_word_pos());
+        }
     }
 
     #[inline]
@@ -109,53 +133,160 @@ impl<const ROUNDS: usize> DjbChaChaRng<ROUNDS> {
         self
     }
 
-    pub fn fill_bytes(&mut self, mut dst: &mut [u8]) {
-        const BLOCK_SIZE: usize = 64;
+    // The position in 32-bit words from the start of the stream, the block
+    // counter times 16 plus the words taken from the buffered block. A
+    // partly consumed word counts as used, as in rand_chacha.
+    pub fn get_word_pos(&self) -> u128 {
+        let buffered = (OUTPUT_LEN_WORDS - self.index) as u128;
+        let counter_words = u128::from(self.core.get_counter()) * OUTPUT_LEN_WORDS as u128;
 
-        // use the remaining buffer
-        if self.buffer_pos < BLOCK_SIZE {
-            let take = dst.len().min(BLOCK_SIZE - self.buffer_pos);
-            dst[..take].copy_from_slice(&self.buffer[self.buffer_pos..self.buffer_pos + take]);
-            self.buffer_pos += take;
-            dst = &mut dst[take..];
+        // the buffered block was generated before the counter wrapped
+        counter_words.wrapping_sub(buffered) & WORD_POS_MASK
+    }
+
+    // Positions past the 64-bit block counter wrap around.
+    pub fn set_word_pos(&mut self, word_pos: u128) {
+        let block = (word_pos / OUTPUT_LEN_WORDS as u128) as u64;
+        let words = (word_pos % OUTPUT_LEN_WORDS as u128) as usize;
+
+        self.core.set_counter(block);
+        self.index = OUTPUT_LEN_WORDS;
+
+        if words > 0 {
+            self.refill();
+            self.index = words;
         }
+    }
+
+    #[inline]
+    pub fn with_word_pos(mut self, word_pos: u128) -> Self {
+        self.set_word_pos(word_pos);
+
+        self
+    }
+
+    #[inline]
+    pub fn next_u32(&mut self) -> u32 {
+        if self.index >= OUTPUT_LEN_WORDS {
+            self.refill();
+        }
+
+        let value = self.buffer[self.index];
+        self.index += 1;
+
+        value
+    }
+
+    // the low word comes first, so the result matches eight bytes of
+    // `fill_bytes` read as little endian
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        match self.index + 2 <= OUTPUT_LEN_WORDS {
+            true => {
+                let low = u64::from(self.buffer[self.index]);
+                let high = u64::from(self.buffer[self.index + 1]);
+                self.index += 2;
+
+                (high << 32) | low
+            }
+            false => u64::from(self.next_u32()) | (u64::from(self.next_u32()) << 32),
+        }
+    }
+
+    // uniform over `a..b` or `a..=b` without modulo bias, panics when the
+    // range is empty
+    pub fn gen_range<T: UniformInt>(&mut self, range: impl SampleRange<T>) -> T {
+        sample::range(range, || self.next_u64())
+    }
+
+    // uniform in [0, 1)
+    #[inline]
+    pub fn next_f32(&mut self) -> f32 {
+        sample::unit_f32(self.next_u32())
+    }
+
+    // uniform in [0, 1)
+    #[inline]
+    pub fn next_f64(&mut self) -> f64 {
+        sample::unit_f64(self.next_u64())
+    }
+
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        sample::shuffle(slice, || self.next_u64());
+    }
+
+    // `None` for an empty slice
+    pub fn choose<'a, T>(&mut self, slice: &'a [T]) -> Option<&'a T> {
+        sample::choose(slice, || self.next_u64())
+    }
+
+    // Output is consumed in whole 32-bit words, the rest of a partly used
+    // word is skipped. This keeps every sequence of calls bit-exact with
+    // rand_chacha's ChaChaRng of the same seed and stream.
+    pub fn fill_bytes(&mut self, mut dst: &mut [u8]) {
+        // use the remaining buffer
+        let taken = self.read_buffer(dst);
+        dst = &mut dst[taken..];
 
         // filling in the main part of the dst
-        while dst.len() >= BLOCK_SIZE {
-            self.core.generate_block(&mut dst[..BLOCK_SIZE]);
-            dst = &mut dst[BLOCK_SIZE..];
+        while dst.len() >= OUTPUT_LEN {
+            self.core.generate_block(&mut dst[..OUTPUT_LEN]);
+            dst = &mut dst[OUTPUT_LEN..];
         }
 
         // filling in the tail
         if !dst.is_empty() {
             self.refill();
-            let n = dst.len();
-            dst.copy_from_slice(&self.buffer[..n]);
-            self.buffer_pos = n;
+            self.read_buffer(dst);
+        }
+    }
+
+    #[inline(always)]
+    fn read_buffer(&mut self, dst: &mut [u8]) -> usize {
+        let words = &self.buffer[self.index..];
+        let len = dst.len().min(words.len() * 4);
+
+        for (chunk, word) in dst[..len].chunks_mut(4).zip(words) {
+            chunk.copy_from_slice(&word.to_le_bytes()[..chunk.len()]);
         }
+        self.index += len.div_ceil(4);
+
+        len
     }
 
     #[inline(always)]
     fn refill(&mut self) {
-        self.core.generate_block(&mut self.buffer);
-        self.buffer_pos = 0;
+        self.core.generate_block_words(&mut self.buffer);
+        self.index = 0;
+    }
+}
+
+// Never fails and always fills the whole buffer. Reads go through
+// `fill_bytes`, so a read that ends inside a word skips the rest of it.
+impl<const ROUNDS: usize> io::Read for DjbChaChaRng<ROUNDS> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.fill_bytes(buf);
+
+        Ok(buf.len())
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        self.fill_bytes(buf);
+
+        Ok(())
     }
 }
 
 #[cfg(feature = "rand")]
 impl<const ROUNDS: usize> RngCore for DjbChaChaRng<ROUNDS> {
+    #[inline]
     fn next_u32(&mut self) -> u32 {
-        let mut buf = [0; (u32::BITS / 8) as usize];
-        self.fill_bytes(&mut buf);
-
-        u32::from_le_bytes(buf)
+        self.next_u32()
     }
 
+    #[inline]
     fn next_u64(&mut self) -> u64 {
-        let mut buf = [0; (u64::BITS / 8) as usize];
-        self.fill_bytes(&mut buf);
-
-        u64::from_le_bytes(buf)
+        self.next_u64()
     }
 
     fn fill_bytes(&mut self, dst: &mut [u8]) {
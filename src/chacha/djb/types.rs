@@ -3,12 +3,12 @@ use zeroize::{Zeroize, ZeroizeOnDrop};
 
 use super::{COUNTER_LEN, NONCE_LEN};
 
-#[derive(Clone, Default)]
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
 #[repr(transparent)]
 pub struct Nonce([u8; NONCE_LEN]);
 
-#[derive(Clone, Default)]
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
 #[repr(transparent)]
 pub struct Counter([u8; COUNTER_LEN]);
@@ -33,5 +33,5 @@ impl Counter {
     }
 }
 
-bytes_wrapper_impl!(Nonce, NONCE_LEN);
-bytes_wrapper_impl!(Counter, COUNTER_LEN);
+bytes_wrapper_impl!(Nonce, NONCE_LEN, public, random);
+bytes_wrapper_impl!(Counter, COUNTER_LEN, public);
@@ -10,6 +10,7 @@ const NONCE_RANGE: Range<usize> = 13..16;
 
 mod cipher;
 mod core;
+mod erasure;
 mod rng;
 mod types;
 
@@ -23,6 +24,11 @@ pub type ChaCha8Rng = ChaChaRng<8>;
 pub type ChaCha12Rng = ChaChaRng<12>;
 pub type ChaCha20Rng = ChaChaRng<20>;
 
+pub type FastKeyErasureRng<const ROUNDS: usize> = erasure::FastKeyErasureRng<ROUNDS>;
+pub type FastKeyErasureChaCha8Rng = FastKeyErasureRng<8>;
+pub type FastKeyErasureChaCha12Rng = FastKeyErasureRng<12>;
+pub type FastKeyErasureChaCha20Rng = FastKeyErasureRng<20>;
+
 pub type ChaChaCore<const ROUNDS: usize> = core::IETFChaChaCore<ROUNDS>;
 pub type ChaCha8Core = ChaChaCore<8>;
 pub type ChaCha12Core = ChaChaCore<12>;
@@ -3,6 +3,7 @@ use zeroize::{Zeroize, ZeroizeOnDrop};
 
 use super::core::IETFChaChaCore;
 use super::{ChaChaCore, Nonce};
+use crate::Error;
 use crate::chacha::consts::*;
 use crate::chacha::{Constants, Key};
 use crate::utils::xor_keystream;
@@ -28,6 +29,11 @@ impl<const ROUNDS: usize> IETFChaCha<ROUNDS> {
         }
     }
 
+    // checks the lengths of untrusted key and nonce bytes
+    pub fn new_from_slices(key: &[u8], nonce: &[u8]) -> Result<Self, Error> {
+        Ok(Self::new(&Key::try_from(key)?, &Nonce::try_from(nonce)?))
+    }
+
     #[inline]
     pub fn get_constants(&self) -> &Constants {
         Constants::from_words_ref(self.core.get_constants())
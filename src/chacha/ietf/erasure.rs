@@ -0,0 +1,114 @@
+#[cfg(feature = "rand")]
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use super::NONCE_LEN;
+use super::core::IETFChaChaCore;
+use crate::chacha::Seed;
+use crate::chacha::consts::*;
+use crate::os;
+
+// 12 blocks as Bernstein suggests, 32 bytes become the next key
+const BATCH_LEN: usize = 12 * OUTPUT_LEN;
+
+// Fast-key-erasure: each batch of keystream starts with the key for the next
+// batch, which replaces the current key at once. Output bytes are wiped from
+// the buffer as they are handed out, so a leaked state reveals nothing about
+// earlier output. The stream id and counter are not part of the API, every
+// batch runs from counter 0 under a fresh key.
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub struct FastKeyErasureRng<const ROUNDS: usize> {
+    core: IETFChaChaCore<ROUNDS>,
+    buffer: [u8; BATCH_LEN],
+    buffer_pos: usize,
+}
+
+impl<const ROUNDS: usize> FastKeyErasureRng<ROUNDS> {
+    pub fn from_seed(seed: &Seed) -> Self {
+        Self {
+            core: IETFChaChaCore::new(seed.bytes(), &[0; NONCE_LEN]),
+            buffer: [0; BATCH_LEN],
+            buffer_pos: BATCH_LEN,
+        }
+    }
+
+    // a fresh seed from the OS, see `os::fill`
+    pub fn from_entropy() -> Result<Self, os::Error> {
+        Ok(Self::from_seed(&Seed::from_os()?))
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf);
+
+        u32::from_le_bytes(buf)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut buf = [0; 8];
+        self.fill_bytes(&mut buf);
+
+        u64::from_le_bytes(buf)
+    }
+
+    pub fn fill_bytes(&mut self, mut dst: &mut [u8]) {
+        while !dst.is_empty() {
+            if self.buffer_pos == BATCH_LEN {
+                self.refill();
+            }
+
+            let take = dst.len().min(BATCH_LEN - self.buffer_pos);
+            let output = &mut self.buffer[self.buffer_pos..self.buffer_pos + take];
+
+            dst[..take].copy_from_slice(output);
+            output.fill(0);
+
+            self.buffer_pos += take;
+            dst = &mut dst[take..];
+        }
+    }
+
+    fn refill(&mut self) {
+        self.core.set_counter(0);
+
+        for block in self.buffer.chunks_exact_mut(OUTPUT_LEN) {
+            self.core.generate_block(block);
+        }
+
+        self.core
+            .set_key(self.buffer[..KEY_LEN].try_into().unwrap());
+        self.buffer[..KEY_LEN].fill(0);
+        self.buffer_pos = KEY_LEN;
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<const ROUNDS: usize> RngCore for FastKeyErasureRng<ROUNDS> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.fill_bytes(dst);
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<const ROUNDS: usize> CryptoRng for FastKeyErasureRng<ROUNDS> {}
+
+#[cfg(feature = "rand")]
+impl<const ROUNDS: usize> SeedableRng for FastKeyErasureRng<ROUNDS> {
+    type Seed = Seed;
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::from_seed(&seed)
+    }
+}
@@ -1,3 +1,5 @@
+use std::io;
+
 #[cfg(feature = "rand")]
 use rand_core::{CryptoRng, RngCore, SeedableRng};
 
@@ -8,29 +10,35 @@ use super::NONCE_LEN;
 use super::core::IETFChaChaCore;
 use super::types::StreamId;
 use crate::chacha::consts::*;
+use crate::chacha::sample::{self, SampleRange, UniformInt};
 use crate::chacha::{Constants, Seed};
+use crate::sha2::Sha256;
+use crate::{Error, os};
 
 const DEFAULT_STREAM_ID: StreamId = StreamId::new([0; NONCE_LEN]);
 
+// separates fork stream ids from other uses of SHA-256
+const FORK_DOMAIN: &[u8] = b"purecrypt chacha fork";
+
+// a 32-bit block counter of 16-word blocks
+const WORD_POS_MASK: u128 = (1 << 36) - 1;
+
 #[derive(Clone)]
 #[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
 pub struct IETFChaChaRng<const ROUNDS: usize> {
     core: IETFChaChaCore<ROUNDS>,
-    buffer: [u8; OUTPUT_LEN],
-    buffer_pos: usize,
+    // one block of keystream words, `pos` is the next unused byte
+    buffer: [u32; OUTPUT_LEN_WORDS],
+    pos: usize,
 }
 
 impl<const ROUNDS: usize> IETFChaChaRng<ROUNDS> {
     pub fn new(seed: &Seed, stream_id: &StreamId) -> Self {
-        let buffer = [0; OUTPUT_LEN];
+        let buffer = [0; OUTPUT_LEN_WORDS];
         let core = IETFChaChaCore::new(seed.bytes(), stream_id.bytes());
-        let buffer_pos = buffer.len();
+        let pos = OUTPUT_LEN;
 
-        Self {
-            core,
-            buffer,
-            buffer_pos,
-        }
+        Self { core, buffer, pos }
     }
 
     #[inline]
@@ -38,6 +46,41 @@ impl<const ROUNDS: usize> IETFChaChaRng<ROUNDS> {
         Self::new(seed, &DEFAULT_STREAM_ID)
     }
 
+    // a fresh seed from the OS, see `os::fill`
+    pub fn from_entropy() -> Result<Self, os::Error> {
+        Ok(Self::from_seed(&Seed::from_os()?))
+    }
+
+    // checks the lengths of untrusted seed and stream id bytes
+    pub fn new_from_slices(seed: &[u8], stream_id: &[u8]) -> Result<Self, Error> {
+        Ok(Self::new(
+            &Seed::try_from(seed)?,
+            &StreamId::try_from(stream_id)?,
+        ))
+    }
+
+    // A generator on the same seed whose stream id is SHA-256 of this one's
+    // stream id and `label`. Equal labels give equal streams, different ones
+    // give independent streams barring a 96-bit collision. The position of
+    // `self` plays no part, and forks can be forked again.
+    pub fn fork(&self, label: &[u8]) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(FORK_DOMAIN);
+        hasher.update(self.get_stream_id().bytes());
+        hasher.update(label);
+        let digest = hasher.finalize();
+
+        let stream_id = StreamId::new(digest[..NONCE_LEN].try_into().unwrap());
+
+        Self::new(self.get_seed(), &stream_id).with_constants(self.get_constants())
+    }
+
+    // one generator per worker index, the i-th is the fork labelled with
+    // `i` as a little-endian u64
+    pub fn split(&self, n: usize) -> Vec<Self> {
+        (0..n as u64).map(|i| self.fork(&i.to_le_bytes())).collect()
+    }
+
     #[inline]
     pub fn get_constants(&self) -> &Constants {
         Constants::from_words_ref(self.core.get_constants())
@@ -106,53 +149,181 @@ impl<const ROUNDS: usize> IETFChaChaRng<ROUNDS> {
         self
     }
 
-    pub fn fill_bytes(&mut self, mut dst: &mut [u8]) {
-        const BLOCK_SIZE: usize = OUTPUT_LEN;
+    // The position in 32-bit words from the start of the stream, the block
+    // counter times 16 plus the words taken from the buffered block. A
+    // partly consumed word counts as used, as in rand_chacha.
+    pub fn get_word_pos(&self) -> u128 {
+        let buffered = ((OUTPUT_LEN - self.pos) / 4) as u128;
+        let counter_words = u128::from(self.core.get_counter()) * OUTPUT_LEN_WORDS as u128;
 
-        // use the remaining buffer
-        if self.buffer_pos < BLOCK_SIZE {
-            let take = dst.len().min(BLOCK_SIZE - self.buffer_pos);
-            dst[..take].copy_from_slice(&self.buffer[self.buffer_pos..self.buffer_pos + take]);
-            self.buffer_pos += take;
-            dst = &mut dst[take..];
+        // the buffered block was generated before the counter wrapped
+        counter_words.wrapping_sub(buffered) & WORD_POS_MASK
+    }
+
+    // Positions past the 32-bit block counter wrap around.
+    pub fn set_word_pos(&mut self, word_pos: u128) {
+        let block = (word_pos / OUTPUT_LEN_WORDS as u128) as u32;
+        let words = (word_pos % OUTPUT_LEN_WORDS as u128) as usize;
+
+        self.core.set_counter(block);
+        self.pos = OUTPUT_LEN;
+
+        if words > 0 {
+            self.refill();
+            self.pos = words * 4;
+        }
+    }
+
+    #[inline]
+    pub fn with_word_pos(mut self, word_pos: u128) -> Self {
+        self.set_word_pos(word_pos);
+
+        self
+    }
+
+    // the next four bytes of `fill_bytes` read as little endian, straight
+    // from the buffer when the position is word aligned
+    #[inline]
+    pub fn next_u32(&mut self) -> u32 {
+        if self.pos % 4 != 0 {
+            let mut bytes = [0; 4];
+            self.fill_bytes(&mut bytes);
+
+            return u32::from_le_bytes(bytes);
+        }
+
+        if self.pos >= OUTPUT_LEN {
+            self.refill();
+        }
+
+        let value = self.buffer[self.pos / 4];
+        self.pos += 4;
+
+        value
+    }
+
+    // the next eight bytes of `fill_bytes` read as little endian
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        match self.pos % 4 == 0 && self.pos + 8 <= OUTPUT_LEN {
+            true => {
+                let low = u64::from(self.buffer[self.pos / 4]);
+                let high = u64::from(self.buffer[self.pos / 4 + 1]);
+                self.pos += 8;
+
+                (high << 32) | low
+            }
+            false => {
+                let mut bytes = [0; 8];
+                self.fill_bytes(&mut bytes);
+
+                u64::from_le_bytes(bytes)
+            }
         }
+    }
+
+    // uniform over `a..b` or `a..=b` without modulo bias, panics when the
+    // range is empty
+    pub fn gen_range<T: UniformInt>(&mut self, range: impl SampleRange<T>) -> T {
+        sample::range(range, || self.next_u64())
+    }
+
+    // uniform in [0, 1)
+    #[inline]
+    pub fn next_f32(&mut self) -> f32 {
+        sample::unit_f32(self.next_u32())
+    }
+
+    // uniform in [0, 1)
+    #[inline]
+    pub fn next_f64(&mut self) -> f64 {
+        sample::unit_f64(self.next_u64())
+    }
+
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        sample::shuffle(slice, || self.next_u64());
+    }
+
+    // `None` for an empty slice
+    pub fn choose<'a, T>(&mut self, slice: &'a [T]) -> Option<&'a T> {
+        sample::choose(slice, || self.next_u64())
+    }
+
+    // Byte-exact: any split of a read gives the same keystream bytes.
+    pub fn fill_bytes(&mut self, mut dst: &mut [u8]) {
+        // use the remaining buffer
+        let taken = self.read_buffer(dst);
+        dst = &mut dst[taken..];
 
         // filling in the main part of the dst
-        while dst.len() >= BLOCK_SIZE {
-            self.core.generate_block(&mut dst[..BLOCK_SIZE]);
-            dst = &mut dst[BLOCK_SIZE..];
+        while dst.len() >= OUTPUT_LEN {
+            self.core.generate_block(&mut dst[..OUTPUT_LEN]);
+            dst = &mut dst[OUTPUT_LEN..];
         }
 
         // filling in the tail
         if !dst.is_empty() {
             self.refill();
-            let n = dst.len();
-            dst.copy_from_slice(&self.buffer[..n]);
-            self.buffer_pos = n;
+            self.read_buffer(dst);
         }
     }
 
+    #[inline(always)]
+    fn read_buffer(&mut self, dst: &mut [u8]) -> usize {
+        let len = dst.len().min(OUTPUT_LEN - self.pos);
+        let mut rest = &mut dst[..len];
+
+        // first the bytes left in a partly read word
+        let offset = self.pos % 4;
+        if offset > 0 && !rest.is_empty() {
+            let word = self.buffer[self.pos / 4].to_le_bytes();
+            let take = rest.len().min(4 - offset);
+            rest[..take].copy_from_slice(&word[offset..offset + take]);
+            rest = &mut rest[take..];
+        }
+
+        let words = &self.buffer[self.pos.div_ceil(4)..];
+        for (chunk, word) in rest.chunks_mut(4).zip(words) {
+            chunk.copy_from_slice(&word.to_le_bytes()[..chunk.len()]);
+        }
+        self.pos += len;
+
+        len
+    }
+
     #[inline(always)]
     fn refill(&mut self) {
-        self.core.generate_block(&mut self.buffer);
-        self.buffer_pos = 0;
+        self.core.generate_block_words(&mut self.buffer);
+        self.pos = 0;
+    }
+}
+
+// Never fails and always fills the whole buffer with the next bytes of
+// `fill_bytes`.
+impl<const ROUNDS: usize> io::Read for IETFChaChaRng<ROUNDS> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.fill_bytes(buf);
+
+        Ok(buf.len())
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        self.fill_bytes(buf);
+
+        Ok(())
     }
 }
 
 #[cfg(feature = "rand")]
 impl<const ROUNDS: usize> RngCore for IETFChaChaRng<ROUNDS> {
+    #[inline]
     fn next_u32(&mut self) -> u32 {
-        let mut buf = [0; (u32::BITS / 8) as usize];
-        self.fill_bytes(&mut buf);
-
-        u32::from_le_bytes(buf)
+        self.next_u32()
     }
 
+    #[inline]
     fn next_u64(&mut self) -> u64 {
-        let mut buf = [0; (u64::BITS / 8) as usize];
-        self.fill_bytes(&mut buf);
-
-        u64::from_le_bytes(buf)
+        self.next_u64()
     }
 
     fn fill_bytes(&mut self, dst: &mut [u8]) {
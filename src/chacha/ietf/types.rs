@@ -3,12 +3,12 @@ use zeroize::{Zeroize, ZeroizeOnDrop};
 
 use super::NONCE_LEN;
 
-#[derive(Clone, Default)]
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
 #[repr(transparent)]
 pub struct Nonce([u8; NONCE_LEN]);
 
-#[derive(Clone, Default)]
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
 #[repr(transparent)]
 pub struct StreamId([u8; NONCE_LEN]);
@@ -35,5 +35,5 @@ impl StreamId {
     }
 }
 
-bytes_wrapper_impl!(Nonce, NONCE_LEN);
-bytes_wrapper_impl!(StreamId, NONCE_LEN);
+bytes_wrapper_impl!(Nonce, NONCE_LEN, public, random);
+bytes_wrapper_impl!(StreamId, NONCE_LEN, public, random);
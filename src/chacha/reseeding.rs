@@ -0,0 +1,225 @@
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "rand")]
+use rand_core::{CryptoRng, RngCore};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use crate::chacha::consts::OUTPUT_LEN;
+use crate::chacha::{ChaChaRng, Seed};
+use crate::os;
+use crate::utils::xor_keystream;
+
+// as in rand's ThreadRng
+pub const DEFAULT_RESEED_BYTES: u64 = 64 * 1024;
+
+// fills a fresh seed, `os::fill` unless overridden
+pub type EntropySource = fn(&mut [u8]) -> Result<(), os::Error>;
+
+// the id of the running process, `std::process::id` unless overridden
+pub type PidSource = fn() -> u32;
+
+// the current time for the reseed interval, `Instant::now` unless overridden
+pub type ClockSource = fn() -> Instant;
+
+// An IETF ChaCha generator that replaces its seed after a number of output
+// bytes, optionally after a time interval, and whenever the process id
+// changes, so a forked child does not go on with its parent's stream. The
+// process id is only read when a draw needs a new keystream block, so a
+// child may still repeat the unread rest of the parent's current block.
+//
+// A new seed is the entropy source's output xored with the generator's own
+// next 32 bytes, so a failing or weak source never makes it worse.
+pub struct ReseedingChaChaRng<const ROUNDS: usize> {
+    rng: ChaChaRng<ROUNDS>,
+    entropy: EntropySource,
+    pid_source: PidSource,
+    pid: u32,
+    // unread bytes of the inner generator's current keystream block
+    buffered: usize,
+    reseed_bytes: u64,
+    bytes_until_reseed: u64,
+    reseed_interval: Option<Duration>,
+    clock: ClockSource,
+    last_reseed: Instant,
+}
+
+pub type ReseedingChaCha8Rng = ReseedingChaChaRng<8>;
+pub type ReseedingChaCha12Rng = ReseedingChaChaRng<12>;
+pub type ReseedingChaCha20Rng = ReseedingChaChaRng<20>;
+
+impl<const ROUNDS: usize> ReseedingChaChaRng<ROUNDS> {
+    pub fn new() -> Result<Self, os::Error> {
+        Self::from_entropy_source(os::fill)
+    }
+
+    pub fn from_entropy_source(entropy: EntropySource) -> Result<Self, os::Error> {
+        let mut seed = Seed::default();
+        entropy(seed.bytes_mut())?;
+
+        Ok(Self {
+            rng: ChaChaRng::from_seed(&seed),
+            entropy,
+            pid_source: std::process::id,
+            pid: std::process::id(),
+            buffered: 0,
+            reseed_bytes: DEFAULT_RESEED_BYTES,
+            bytes_until_reseed: DEFAULT_RESEED_BYTES,
+            reseed_interval: None,
+            clock: Instant::now,
+            last_reseed: Instant::now(),
+        })
+    }
+
+    #[inline]
+    pub fn get_reseed_bytes(&self) -> u64 {
+        self.reseed_bytes
+    }
+
+    // takes effect from the next reseed on
+    #[inline]
+    pub fn set_reseed_bytes(&mut self, reseed_bytes: u64) {
+        self.reseed_bytes = reseed_bytes;
+        self.bytes_until_reseed = self.bytes_until_reseed.min(reseed_bytes);
+    }
+
+    #[inline]
+    pub fn with_reseed_bytes(mut self, reseed_bytes: u64) -> Self {
+        self.set_reseed_bytes(reseed_bytes);
+
+        self
+    }
+
+    #[inline]
+    pub fn get_reseed_interval(&self) -> Option<Duration> {
+        self.reseed_interval
+    }
+
+    // `None` turns time-based reseeding off
+    #[inline]
+    pub fn set_reseed_interval(&mut self, interval: Option<Duration>) {
+        self.reseed_interval = interval;
+    }
+
+    #[inline]
+    pub fn with_reseed_interval(mut self, interval: Option<Duration>) -> Self {
+        self.set_reseed_interval(interval);
+
+        self
+    }
+
+    // the current process id is read from the new source right away
+    #[inline]
+    pub fn set_pid_source(&mut self, pid_source: PidSource) {
+        self.pid_source = pid_source;
+        self.pid = pid_source();
+    }
+
+    #[inline]
+    pub fn with_pid_source(mut self, pid_source: PidSource) -> Self {
+        self.set_pid_source(pid_source);
+
+        self
+    }
+
+    // the interval is measured from the new source's current time
+    #[inline]
+    pub fn set_clock_source(&mut self, clock: ClockSource) {
+        self.clock = clock;
+        self.last_reseed = clock();
+    }
+
+    #[inline]
+    pub fn with_clock_source(mut self, clock: ClockSource) -> Self {
+        self.set_clock_source(clock);
+
+        self
+    }
+
+    pub fn reseed(&mut self) -> Result<(), os::Error> {
+        let mut seed = Seed::default();
+        (self.entropy)(seed.bytes_mut())?;
+
+        let mut current = [0; 32];
+        self.rng.fill_bytes(&mut current);
+        xor_keystream(&current, seed.bytes_mut());
+
+        #[cfg(feature = "zeroize")]
+        current.zeroize();
+
+        self.rng = ChaChaRng::from_seed(&seed);
+        self.pid = (self.pid_source)();
+        self.buffered = 0;
+        self.bytes_until_reseed = self.reseed_bytes;
+        self.last_reseed = (self.clock)();
+
+        Ok(())
+    }
+
+    // fails only when a due reseed cannot reach the entropy source, no
+    // output is produced then
+    pub fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), os::Error> {
+        self.before_draw(dst.len())?;
+        self.rng.fill_bytes(dst);
+
+        Ok(())
+    }
+
+    // panics when a due reseed cannot reach the entropy source
+    pub fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.try_fill_bytes(dst).expect("reseeding failed");
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        self.before_draw(4).expect("reseeding failed");
+        self.rng.next_u32()
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.before_draw(8).expect("reseeding failed");
+        self.rng.next_u64()
+    }
+
+    #[inline(always)]
+    fn before_draw(&mut self, len: usize) -> Result<(), os::Error> {
+        // the pid is only read when new keystream is about to be generated
+        let forked = len > self.buffered && (self.pid_source)() != self.pid;
+        let exhausted = self.bytes_until_reseed < len as u64;
+        let expired = self.reseed_interval.is_some_and(|interval| {
+            (self.clock)().saturating_duration_since(self.last_reseed) >= interval
+        });
+
+        if forked || exhausted || expired {
+            self.reseed()?;
+        }
+
+        self.bytes_until_reseed = self.bytes_until_reseed.saturating_sub(len as u64);
+        self.buffered = match len > self.buffered {
+            true => (OUTPUT_LEN - (len - self.buffered) % OUTPUT_LEN) % OUTPUT_LEN,
+            false => self.buffered - len,
+        };
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<const ROUNDS: usize> RngCore for ReseedingChaChaRng<ROUNDS> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.fill_bytes(dst);
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<const ROUNDS: usize> CryptoRng for ReseedingChaChaRng<ROUNDS> {}
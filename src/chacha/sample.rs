@@ -0,0 +1,136 @@
+use std::ops::{Range, RangeInclusive};
+
+// `UniformInt` and `SampleRange` are implemented only in this module
+mod private {
+    pub trait Sealed {}
+}
+
+// Integers `gen_range` can draw. A value is mapped to its unsigned distance
+// from the low end of the range, so signed ranges need no special casing.
+pub trait UniformInt: Copy + PartialOrd + private::Sealed {
+    fn offset_from(self, low: Self) -> u64;
+
+    fn add_offset(low: Self, offset: u64) -> Self;
+
+    fn pred(self) -> Self;
+}
+
+macro_rules! uniform_int_impl {
+    ($($int:ty => $unsigned:ty),+) => {
+        $(
+            impl private::Sealed for $int {}
+
+            impl UniformInt for $int {
+                #[inline(always)]
+                fn offset_from(self, low: Self) -> u64 {
+                    self.wrapping_sub(low) as $unsigned as u64
+                }
+
+                #[inline(always)]
+                fn add_offset(low: Self, offset: u64) -> Self {
+                    low.wrapping_add(offset as $int)
+                }
+
+                #[inline(always)]
+                fn pred(self) -> Self {
+                    self.wrapping_sub(1)
+                }
+            }
+        )+
+    };
+}
+
+uniform_int_impl!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, isize => usize
+);
+
+// `a..b` and `a..=b` for `gen_range`
+pub trait SampleRange<T>: private::Sealed {
+    // the inclusive bounds, `None` when the range is empty
+    fn bounds(self) -> Option<(T, T)>;
+}
+
+impl<T: UniformInt> private::Sealed for Range<T> {}
+
+impl<T: UniformInt> private::Sealed for RangeInclusive<T> {}
+
+impl<T: UniformInt> SampleRange<T> for Range<T> {
+    fn bounds(self) -> Option<(T, T)> {
+        match self.start < self.end {
+            true => Some((self.start, self.end.pred())),
+            false => None,
+        }
+    }
+}
+
+impl<T: UniformInt> SampleRange<T> for RangeInclusive<T> {
+    fn bounds(self) -> Option<(T, T)> {
+        let (low, high) = self.into_inner();
+
+        match low <= high {
+            true => Some((low, high)),
+            false => None,
+        }
+    }
+}
+
+// Uniform in [0, n) for n > 0, Lemire's multiply-and-reject: the high half
+// of a 128-bit product is taken unless the low half falls in the biased
+// sliver below 2^64 mod n, which is rejected.
+#[inline]
+pub(crate) fn below(n: u64, mut next_u64: impl FnMut() -> u64) -> u64 {
+    let mut product = u128::from(next_u64()) * u128::from(n);
+
+    if (product as u64) < n {
+        let threshold = n.wrapping_neg() % n;
+
+        while (product as u64) < threshold {
+            product = u128::from(next_u64()) * u128::from(n);
+        }
+    }
+
+    (product >> 64) as u64
+}
+
+pub(crate) fn range<T: UniformInt>(
+    range: impl SampleRange<T>,
+    mut next_u64: impl FnMut() -> u64,
+) -> T {
+    let (low, high) = range.bounds().expect("cannot sample an empty range");
+
+    let offset = match high.offset_from(low) {
+        // the whole of a 64-bit type
+        u64::MAX => next_u64(),
+        span => below(span + 1, next_u64),
+    };
+
+    T::add_offset(low, offset)
+}
+
+// the top 24 and 53 bits as the mantissa, every value a multiple of 2^-24
+// and 2^-53
+#[inline(always)]
+pub(crate) fn unit_f32(word: u32) -> f32 {
+    (word >> 8) as f32 * (1.0 / (1_u32 << 24) as f32)
+}
+
+#[inline(always)]
+pub(crate) fn unit_f64(word: u64) -> f64 {
+    (word >> 11) as f64 * (1.0 / (1_u64 << 53) as f64)
+}
+
+// Fisher-Yates from the back
+pub(crate) fn shuffle<T>(slice: &mut [T], mut next_u64: impl FnMut() -> u64) {
+    for i in (1..slice.len()).rev() {
+        let j = below(i as u64 + 1, &mut next_u64);
+        slice.swap(i, j as usize);
+    }
+}
+
+pub(crate) fn choose<T>(slice: &[T], next_u64: impl FnMut() -> u64) -> Option<&T> {
+    match slice.is_empty() {
+        true => None,
+        false => Some(&slice[below(slice.len() as u64, next_u64) as usize]),
+    }
+}
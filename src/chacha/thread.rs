@@ -0,0 +1,79 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+#[cfg(feature = "rand")]
+use rand_core::{CryptoRng, RngCore};
+
+use crate::chacha::ReseedingChaCha12Rng;
+
+thread_local! {
+    static THREAD_RNG: Rc<RefCell<ReseedingChaCha12Rng>> = Rc::new(RefCell::new(
+        ReseedingChaCha12Rng::new().expect("os entropy is unavailable"),
+    ));
+}
+
+// A handle to the calling thread's generator, a ChaCha12 stream seeded from
+// the OS on first use and reseeded every `DEFAULT_RESEED_BYTES` and after a
+// fork. Handles are cheap to clone and stay on their thread.
+#[derive(Clone)]
+pub struct ThreadRng {
+    rng: Rc<RefCell<ReseedingChaCha12Rng>>,
+}
+
+// panics when the OS has no entropy for the first seed or a reseed
+pub fn thread_rng() -> ThreadRng {
+    ThreadRng {
+        rng: THREAD_RNG.with(Rc::clone),
+    }
+}
+
+impl ThreadRng {
+    #[inline]
+    pub fn next_u32(&mut self) -> u32 {
+        self.rng.borrow_mut().next_u32()
+    }
+
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        self.rng.borrow_mut().next_u64()
+    }
+
+    #[inline]
+    pub fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.rng.borrow_mut().fill_bytes(dst);
+    }
+}
+
+impl Default for ThreadRng {
+    fn default() -> Self {
+        thread_rng()
+    }
+}
+
+// the state never shows up in logs
+impl fmt::Debug for ThreadRng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ThreadRng { .. }")
+    }
+}
+
+#[cfg(feature = "rand")]
+impl RngCore for ThreadRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.fill_bytes(dst);
+    }
+}
+
+#[cfg(feature = "rand")]
+impl CryptoRng for ThreadRng {}
@@ -2,8 +2,16 @@
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
 use super::consts::*;
+use crate::sha2::Sha256;
 
-#[derive(Clone)]
+// the PCG32 parameters of rand_core's `SeedableRng::seed_from_u64`
+const PCG_MUL: u64 = 6364136223846793005;
+const PCG_INC: u64 = 11634580027462260723;
+
+// separates label seeds from other uses of SHA-256
+const LABEL_DOMAIN: &[u8] = b"purecrypt chacha seed label";
+
+#[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
 #[repr(transparent)]
 pub struct Constants([u8; CONSTANTS_LEN]);
@@ -18,6 +26,13 @@ pub struct Key([u8; KEY_LEN]);
 #[repr(transparent)]
 pub struct Seed([u8; KEY_LEN]);
 
+// the output of one `BlockRngCore::generate`, arrays this long have no
+// `Default` of their own
+#[cfg(feature = "rand")]
+#[derive(Clone)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub struct Results([u32; RESULTS_LEN_WORDS]);
+
 impl Constants {
     pub fn is_default(&self) -> bool {
         self.0 == DEFAULT_CONSTANTS
@@ -36,6 +51,42 @@ impl Key {
 }
 
 impl Seed {
+    pub fn from_os() -> Result<Self, crate::os::Error> {
+        let mut seed = Self::default();
+        crate::os::fill(&mut seed.0)?;
+
+        Ok(seed)
+    }
+
+    // Expands `state` with PCG32 exactly as rand_core's `seed_from_u64`
+    // does, so `from_seed(&Seed::from_u64(n))` matches `seed_from_u64(n)`.
+    // For tests and simulations only, there are just 2^64 such seeds.
+    pub fn from_u64(mut state: u64) -> Self {
+        let mut seed = Self::default();
+
+        for chunk in seed.0.chunks_exact_mut(4) {
+            // the state advances first to get away from low-weight inputs
+            state = state.wrapping_mul(PCG_MUL).wrapping_add(PCG_INC);
+
+            let xorshifted = (((state >> 18) ^ state) >> 27) as u32;
+            let rotation = (state >> 59) as u32;
+            chunk.copy_from_slice(&xorshifted.rotate_right(rotation).to_le_bytes());
+        }
+
+        seed
+    }
+
+    // SHA-256 of a domain tag and `label`, reproducible test fixtures named
+    // by a string. Not a password hash, a guessable label gives a guessable
+    // seed.
+    pub fn from_label(label: &str) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(LABEL_DOMAIN);
+        hasher.update(label.as_bytes());
+
+        Self(hasher.finalize())
+    }
+
     pub fn into_key(&self) -> Key {
         Key(self.0)
     }
@@ -52,6 +103,36 @@ impl Default for Constants {
     }
 }
 
-bytes_wrapper_impl!(Constants, CONSTANTS_LEN);
-bytes_wrapper_impl!(Key, KEY_LEN);
-bytes_wrapper_impl!(Seed, KEY_LEN);
+#[cfg(feature = "rand")]
+impl Results {
+    pub(crate) fn blocks_mut(&mut self) -> impl Iterator<Item = &mut [u32; OUTPUT_LEN_WORDS]> {
+        self.0
+            .chunks_exact_mut(OUTPUT_LEN_WORDS)
+            .map(|block| block.try_into().unwrap())
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Default for Results {
+    fn default() -> Self {
+        Self([0; RESULTS_LEN_WORDS])
+    }
+}
+
+#[cfg(feature = "rand")]
+impl AsRef<[u32]> for Results {
+    fn as_ref(&self) -> &[u32] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "rand")]
+impl AsMut<[u32]> for Results {
+    fn as_mut(&mut self) -> &mut [u32] {
+        &mut self.0
+    }
+}
+
+bytes_wrapper_impl!(Constants, CONSTANTS_LEN, public);
+bytes_wrapper_impl!(Key, KEY_LEN, secret, random);
+bytes_wrapper_impl!(Seed, KEY_LEN, secret, random);
@@ -0,0 +1,91 @@
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+pub use self::types::XNonce;
+
+use super::consts::*;
+use super::ietf::{ChaCha, Nonce};
+use super::{Key, column_round, diagonal_round};
+use crate::Error;
+use crate::utils::{bytes_to_words, words_to_bytes};
+
+const XNONCE_LEN: usize = 24;
+pub const HCHACHA_INPUT_LEN: usize = 16;
+
+mod types;
+
+pub type XChaCha8 = XChaCha<8>;
+pub type XChaCha12 = XChaCha<12>;
+pub type XChaCha20 = XChaCha<20>;
+
+// HChaCha from draft-irtf-cfrg-xchacha section 2.2: the ChaCha rounds over
+// key and 128-bit input without the final addition, returning the first and
+// last rows as a new key
+pub fn hchacha<const ROUNDS: usize>(key: &Key, input: &[u8; HCHACHA_INPUT_LEN]) -> Key {
+    let mut state = [0_u32; STATE_LEN_WORDS];
+
+    bytes_to_words(&DEFAULT_CONSTANTS, &mut state[CONSTANTS_RANGE]);
+    bytes_to_words(key.bytes(), &mut state[KEY_RANGE]);
+    bytes_to_words(input, &mut state[12..16]);
+
+    for i in 0..ROUNDS {
+        match i % 2 == 0 {
+            true => column_round(&mut state),
+            false => diagonal_round(&mut state),
+        }
+    }
+
+    let mut subkey = Key::default();
+    words_to_bytes(&state[0..4], &mut subkey.bytes_mut()[..16]);
+    words_to_bytes(&state[12..16], &mut subkey.bytes_mut()[16..]);
+
+    #[cfg(feature = "zeroize")]
+    state.zeroize();
+
+    subkey
+}
+
+// XChaCha with a 192-bit nonce: the first 128 bits derive a subkey through
+// HChaCha, the remaining 64 bits are the tail of an IETF nonce
+#[derive(Clone)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub struct XChaCha<const ROUNDS: usize>(ChaCha<ROUNDS>);
+
+impl<const ROUNDS: usize> XChaCha<ROUNDS> {
+    pub fn new(key: &Key, nonce: &XNonce) -> Self {
+        let (prefix, suffix) = nonce.bytes().split_at(HCHACHA_INPUT_LEN);
+        let subkey = hchacha::<ROUNDS>(key, prefix.try_into().unwrap());
+
+        let mut ietf_nonce = Nonce::default();
+        ietf_nonce.bytes_mut()[4..].copy_from_slice(suffix);
+
+        Self(ChaCha::new(&subkey, &ietf_nonce))
+    }
+
+    // checks the lengths of untrusted key and nonce bytes
+    pub fn new_from_slices(key: &[u8], nonce: &[u8]) -> Result<Self, Error> {
+        Ok(Self::new(&Key::try_from(key)?, &XNonce::try_from(nonce)?))
+    }
+
+    #[inline]
+    pub fn get_counter(&self) -> u32 {
+        self.0.get_counter()
+    }
+
+    #[inline]
+    pub fn set_counter(&mut self, counter: u32) {
+        self.0.set_counter(counter);
+    }
+
+    #[inline]
+    pub fn with_counter(mut self, counter: u32) -> Self {
+        self.set_counter(counter);
+
+        self
+    }
+
+    #[inline]
+    pub fn apply_keystream(&mut self, dst: &mut [u8]) {
+        self.0.apply_keystream(dst);
+    }
+}
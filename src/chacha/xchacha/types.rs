@@ -0,0 +1,11 @@
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use super::XNONCE_LEN;
+
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+#[repr(transparent)]
+pub struct XNonce([u8; XNONCE_LEN]);
+
+bytes_wrapper_impl!(XNonce, XNONCE_LEN, public, random);
@@ -0,0 +1,79 @@
+use std::hint::black_box;
+
+// Equality whose running time depends only on the lengths of the inputs,
+// never on their contents.
+pub trait ConstantTimeEq {
+    fn ct_eq(&self, other: &Self) -> bool;
+
+    #[inline]
+    fn ct_ne(&self, other: &Self) -> bool {
+        !self.ct_eq(other)
+    }
+}
+
+// Branch-free selection: `conditional_select(a, b, choice)` is `b` when
+// `choice` is set and `a` otherwise.
+pub trait ConditionallySelectable: Sized {
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self;
+
+    #[inline]
+    fn conditional_assign(&mut self, other: &Self, choice: bool) {
+        *self = Self::conditional_select(self, other, choice);
+    }
+
+    #[inline]
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: bool) {
+        let new_a = Self::conditional_select(a, b, choice);
+        *b = Self::conditional_select(b, a, choice);
+        *a = new_a;
+    }
+}
+
+macro_rules! ct_int_impl {
+    ($($int:ty),+) => {
+        $(
+            impl ConstantTimeEq for $int {
+                #[inline]
+                fn ct_eq(&self, other: &Self) -> bool {
+                    black_box(self ^ other) == 0
+                }
+            }
+
+            impl ConditionallySelectable for $int {
+                #[inline]
+                fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+                    // all ones when `choice` is set, hidden from the optimizer
+                    let mask = <$int>::wrapping_neg(black_box(<$int>::from(choice)));
+
+                    a ^ (mask & (a ^ b))
+                }
+            }
+        )+
+    };
+}
+
+ct_int_impl!(u8, u16, u32, u64, u128);
+
+impl ConstantTimeEq for [u8] {
+    fn ct_eq(&self, other: &Self) -> bool {
+        let acc = self
+            .iter()
+            .zip(other)
+            .fold(0_u8, |acc, (a, b)| acc | (a ^ b));
+
+        (self.len() == other.len()) & (black_box(acc) == 0)
+    }
+}
+
+impl<const N: usize> ConstantTimeEq for [u8; N] {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> bool {
+        self[..].ct_eq(&other[..])
+    }
+}
+
+impl<const N: usize> ConditionallySelectable for [u8; N] {
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        std::array::from_fn(|i| u8::conditional_select(&a[i], &b[i], choice))
+    }
+}
@@ -0,0 +1,8 @@
+pub(crate) use self::edwards::EdwardsPoint;
+pub(crate) use self::field::FieldElement;
+pub use self::scalar::Scalar;
+
+pub(crate) mod edwards;
+pub(crate) mod field;
+pub(crate) mod montgomery;
+pub(crate) mod scalar;
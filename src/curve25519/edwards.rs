@@ -0,0 +1,247 @@
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use super::field::{D, D2};
+use super::{FieldElement, Scalar};
+
+// the RFC 8032 base point, y = 4/5 with positive x
+const BASEPOINT: EdwardsPoint = EdwardsPoint {
+    x: FieldElement([
+        0x62d608f25d51a,
+        0x412a4b4f6592a,
+        0x75b7171a4b31d,
+        0x1ff60527118fe,
+        0x216936d3cd6e5,
+    ]),
+    y: FieldElement([
+        0x6666666666658,
+        0x4cccccccccccc,
+        0x1999999999999,
+        0x3333333333333,
+        0x6666666666666,
+    ]),
+    z: FieldElement::ONE,
+    t: FieldElement([
+        0x68ab3a5b7dda3,
+        0xeea2a5eadbb,
+        0x2af8df483c27e,
+        0x332b375274732,
+        0x67875f0fd78b7,
+    ]),
+};
+
+// A point on edwards25519 in extended coordinates, x = X/Z, y = Y/Z and
+// x * y = T/Z.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize))]
+pub(crate) struct EdwardsPoint {
+    pub(crate) x: FieldElement,
+    pub(crate) y: FieldElement,
+    pub(crate) z: FieldElement,
+    pub(crate) t: FieldElement,
+}
+
+impl EdwardsPoint {
+    pub(crate) const IDENTITY: Self = Self {
+        x: FieldElement::ZERO,
+        y: FieldElement::ONE,
+        z: FieldElement::ONE,
+        t: FieldElement::ZERO,
+    };
+
+    pub(crate) const fn basepoint() -> Self {
+        BASEPOINT
+    }
+
+    // Decodes per RFC 8032 section 5.1.3, but leniently: y is reduced mod p
+    // instead of being rejected and a negative zero x is accepted. Callers
+    // that need canonical encodings compare against `compress`.
+    pub(crate) fn decompress(bytes: &[u8; 32]) -> Option<Self> {
+        let y = FieldElement::from_bytes(bytes);
+        let sign = (bytes[31] >> 7) == 1;
+
+        // x^2 = (y^2 - 1) / (d * y^2 + 1)
+        let yy = y.square();
+        let u = yy.sub(&FieldElement::ONE);
+        let v = yy.mul(&D).add(&FieldElement::ONE);
+        let (is_square, mut x) = FieldElement::sqrt_ratio_i(&u, &v);
+
+        if !is_square {
+            return None;
+        }
+
+        x.conditional_negate(sign);
+
+        Some(Self {
+            x,
+            y,
+            z: FieldElement::ONE,
+            t: x.mul(&y),
+        })
+    }
+
+    pub(crate) fn compress(&self) -> [u8; 32] {
+        let recip = self.z.invert();
+        let x = self.x.mul(&recip);
+        let y = self.y.mul(&recip);
+
+        let mut bytes = y.to_bytes();
+        bytes[31] ^= (x.is_negative() as u8) << 7;
+
+        bytes
+    }
+
+    // add-2008-hwcd-3, complete for edwards25519
+    pub(crate) fn add(&self, rhs: &Self) -> Self {
+        let a = self.y.sub(&self.x).mul(&rhs.y.sub(&rhs.x));
+        let b = self.y.add(&self.x).mul(&rhs.y.add(&rhs.x));
+        let c = self.t.mul(&D2).mul(&rhs.t);
+        let d = self.z.add(&self.z).mul(&rhs.z);
+        let e = b.sub(&a);
+        let f = d.sub(&c);
+        let g = d.add(&c);
+        let h = b.add(&a);
+
+        Self {
+            x: e.mul(&f),
+            y: g.mul(&h),
+            z: f.mul(&g),
+            t: e.mul(&h),
+        }
+    }
+
+    pub(crate) fn sub(&self, rhs: &Self) -> Self {
+        self.add(&rhs.neg())
+    }
+
+    // dbl-2008-hwcd with a = -1
+    pub(crate) fn double(&self) -> Self {
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = self.z.square().add(&self.z.square());
+        let d = a.neg();
+        let e = self.x.add(&self.y).square().sub(&a).sub(&b);
+        let g = d.add(&b);
+        let f = g.sub(&c);
+        let h = d.sub(&b);
+
+        Self {
+            x: e.mul(&f),
+            y: g.mul(&h),
+            z: f.mul(&g),
+            t: e.mul(&h),
+        }
+    }
+
+    pub(crate) fn neg(&self) -> Self {
+        Self {
+            x: self.x.neg(),
+            y: self.y,
+            z: self.z,
+            t: self.t.neg(),
+        }
+    }
+
+    // Constant-time multiplication by a little-endian 256-bit integer using
+    // fixed 4-bit windows and a full table scan per window.
+    pub(crate) fn mul_bytes(&self, scalar: &[u8; 32]) -> Self {
+        let mut table = [Self::IDENTITY; 16];
+        for i in 1..16 {
+            table[i] = table[i - 1].add(self);
+        }
+
+        let mut acc = Self::IDENTITY;
+
+        for i in (0..64).rev() {
+            acc = acc.double().double().double().double();
+
+            let nibble = (scalar[i / 2] >> ((i % 2) * 4)) & 0x0f;
+            let mut selected = Self::IDENTITY;
+            for (j, entry) in table.iter().enumerate() {
+                // equal iff (j ^ nibble) - 1 underflows
+                let choice = ((j as u32 ^ u32::from(nibble)).wrapping_sub(1) >> 31) == 1;
+                selected.conditional_assign(entry, choice);
+            }
+
+            acc = acc.add(&selected);
+        }
+
+        #[cfg(feature = "zeroize")]
+        table.zeroize();
+
+        acc
+    }
+
+    pub(crate) fn mul(&self, scalar: &Scalar) -> Self {
+        self.mul_bytes(scalar.as_bytes())
+    }
+
+    pub(crate) fn mul_base(scalar: &Scalar) -> Self {
+        BASEPOINT.mul(scalar)
+    }
+
+    // sum(scalars[i] * points[i]) by Straus' method: one shared chain of
+    // doublings and a 4-bit window table per point. Variable time, so only
+    // for public inputs such as signatures being verified.
+    pub(crate) fn vartime_multiscalar_mul(scalars: &[Scalar], points: &[Self]) -> Self {
+        debug_assert_eq!(scalars.len(), points.len());
+
+        // tables[k][j] = (j + 1) * points[k]
+        let tables: Vec<[Self; 15]> = points
+            .iter()
+            .map(|point| {
+                let mut table = [*point; 15];
+                for j in 1..15 {
+                    table[j] = table[j - 1].add(point);
+                }
+
+                table
+            })
+            .collect();
+
+        let mut acc = Self::IDENTITY;
+
+        for i in (0..64).rev() {
+            acc = acc.double().double().double().double();
+
+            for (scalar, table) in scalars.iter().zip(&tables) {
+                // short scalars such as 128-bit coefficients cost no additions
+                // for their zero high nibbles
+                let nibble = (scalar.as_bytes()[i / 2] >> ((i % 2) * 4)) & 0x0f;
+                if nibble != 0 {
+                    acc = acc.add(&table[usize::from(nibble) - 1]);
+                }
+            }
+        }
+
+        acc
+    }
+
+    pub(crate) fn mul_by_cofactor(&self) -> Self {
+        self.double().double().double()
+    }
+
+    pub(crate) fn is_identity(&self) -> bool {
+        self.ct_eq(&Self::IDENTITY)
+    }
+
+    pub(crate) fn is_small_order(&self) -> bool {
+        self.mul_by_cofactor().is_identity()
+    }
+
+    // compares projectively: X1 * Z2 == X2 * Z1 and Y1 * Z2 == Y2 * Z1
+    pub(crate) fn ct_eq(&self, rhs: &Self) -> bool {
+        let x_eq = self.x.mul(&rhs.z).ct_eq(&rhs.x.mul(&self.z));
+        let y_eq = self.y.mul(&rhs.z).ct_eq(&rhs.y.mul(&self.z));
+
+        x_eq & y_eq
+    }
+
+    #[inline(always)]
+    pub(crate) fn conditional_assign(&mut self, rhs: &Self, choice: bool) {
+        self.x.conditional_assign(&rhs.x, choice);
+        self.y.conditional_assign(&rhs.y, choice);
+        self.z.conditional_assign(&rhs.z, choice);
+        self.t.conditional_assign(&rhs.t, choice);
+    }
+}
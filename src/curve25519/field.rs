@@ -0,0 +1,341 @@
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+const LIMB_BITS: u32 = 51;
+const LIMB_MASK: u64 = (1 << LIMB_BITS) - 1;
+
+// 16 * p in radix 2^51, large enough to keep subtraction non-negative for
+// any partially reduced operand
+const P_TIMES_16: [u64; 5] = [
+    16 * ((1 << 51) - 19),
+    16 * ((1 << 51) - 1),
+    16 * ((1 << 51) - 1),
+    16 * ((1 << 51) - 1),
+    16 * ((1 << 51) - 1),
+];
+
+// -121665 / 121666, the edwards25519 curve constant
+pub(crate) const D: FieldElement = FieldElement([
+    0x34dca135978a3,
+    0x1a8283b156ebd,
+    0x5e7a26001c029,
+    0x739c663a03cbb,
+    0x52036cee2b6ff,
+]);
+
+pub(crate) const D2: FieldElement = FieldElement([
+    0x69b9426b2f159,
+    0x35050762add7a,
+    0x3cf44c0038052,
+    0x6738cc7407977,
+    0x2406d9dc56dff,
+]);
+
+// sqrt(-1) = 2^((p - 1) / 4)
+pub(crate) const SQRT_M1: FieldElement = FieldElement([
+    0x61b274a0ea0b0,
+    0xd5a5fc8f189d,
+    0x7ef5e9cbd0c60,
+    0x78595a6804c9e,
+    0x2b8324804fc1d,
+]);
+
+// An element of GF(2^255 - 19) in radix 2^51. Limbs are kept below 2^52
+// between operations, full reduction happens only in `to_bytes`.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize))]
+pub(crate) struct FieldElement(pub(crate) [u64; 5]);
+
+impl FieldElement {
+    pub(crate) const ZERO: Self = Self([0, 0, 0, 0, 0]);
+    pub(crate) const ONE: Self = Self([1, 0, 0, 0, 0]);
+
+    pub(crate) fn from_bytes(bytes: &[u8; 32]) -> Self {
+        let load = |i: usize| u64::from_le_bytes(bytes[i..i + 8].try_into().unwrap());
+
+        // the top bit is ignored as required by RFC 7748 and RFC 8032
+        Self([
+            load(0) & LIMB_MASK,
+            (load(6) >> 3) & LIMB_MASK,
+            (load(12) >> 6) & LIMB_MASK,
+            (load(19) >> 1) & LIMB_MASK,
+            (load(24) >> 12) & LIMB_MASK,
+        ])
+    }
+
+    pub(crate) fn to_bytes(self) -> [u8; 32] {
+        let mut limbs = Self::weak_reduce(self.0);
+
+        // compute q = floor((h + 19) / p), which is 0 or 1
+        let mut q = (limbs[0] + 19) >> LIMB_BITS;
+        q = (limbs[1] + q) >> LIMB_BITS;
+        q = (limbs[2] + q) >> LIMB_BITS;
+        q = (limbs[3] + q) >> LIMB_BITS;
+        q = (limbs[4] + q) >> LIMB_BITS;
+
+        // h - q * p = h + 19 * q - q * 2^255
+        limbs[0] += 19 * q;
+
+        limbs[1] += limbs[0] >> LIMB_BITS;
+        limbs[0] &= LIMB_MASK;
+        limbs[2] += limbs[1] >> LIMB_BITS;
+        limbs[1] &= LIMB_MASK;
+        limbs[3] += limbs[2] >> LIMB_BITS;
+        limbs[2] &= LIMB_MASK;
+        limbs[4] += limbs[3] >> LIMB_BITS;
+        limbs[3] &= LIMB_MASK;
+        limbs[4] &= LIMB_MASK;
+
+        let mut out = [0_u8; 32];
+        let mut acc = 0_u128;
+        let mut acc_bits = 0;
+        let mut pos = 0;
+
+        for limb in limbs {
+            acc |= u128::from(limb) << acc_bits;
+            acc_bits += LIMB_BITS;
+
+            while acc_bits >= 8 {
+                out[pos] = acc as u8;
+                acc >>= 8;
+                acc_bits -= 8;
+                pos += 1;
+            }
+        }
+        out[pos] = acc as u8;
+
+        out
+    }
+
+    #[inline(always)]
+    fn weak_reduce(mut limbs: [u64; 5]) -> [u64; 5] {
+        let c0 = limbs[0] >> LIMB_BITS;
+        let c1 = limbs[1] >> LIMB_BITS;
+        let c2 = limbs[2] >> LIMB_BITS;
+        let c3 = limbs[3] >> LIMB_BITS;
+        let c4 = limbs[4] >> LIMB_BITS;
+
+        limbs[0] &= LIMB_MASK;
+        limbs[1] &= LIMB_MASK;
+        limbs[2] &= LIMB_MASK;
+        limbs[3] &= LIMB_MASK;
+        limbs[4] &= LIMB_MASK;
+
+        limbs[0] += c4 * 19;
+        limbs[1] += c0;
+        limbs[2] += c1;
+        limbs[3] += c2;
+        limbs[4] += c3;
+
+        limbs
+    }
+
+    #[inline(always)]
+    pub(crate) fn add(&self, rhs: &Self) -> Self {
+        let mut limbs = [0_u64; 5];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            *limb = self.0[i] + rhs.0[i];
+        }
+
+        Self(Self::weak_reduce(limbs))
+    }
+
+    #[inline(always)]
+    pub(crate) fn sub(&self, rhs: &Self) -> Self {
+        let mut limbs = [0_u64; 5];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            *limb = (self.0[i] + P_TIMES_16[i]) - rhs.0[i];
+        }
+
+        Self(Self::weak_reduce(limbs))
+    }
+
+    #[inline(always)]
+    pub(crate) fn neg(&self) -> Self {
+        Self::ZERO.sub(self)
+    }
+
+    #[inline(always)]
+    pub(crate) fn mul(&self, rhs: &Self) -> Self {
+        #[inline(always)]
+        fn m(a: u64, b: u64) -> u128 {
+            u128::from(a) * u128::from(b)
+        }
+
+        let a = &self.0;
+        let b = &rhs.0;
+
+        // 2^255 = 19 (mod p), so the high products wrap around times 19
+        let b1_19 = b[1] * 19;
+        let b2_19 = b[2] * 19;
+        let b3_19 = b[3] * 19;
+        let b4_19 = b[4] * 19;
+
+        let c0 = m(a[0], b[0]) + m(a[4], b1_19) + m(a[3], b2_19) + m(a[2], b3_19) + m(a[1], b4_19);
+        let c1 = m(a[1], b[0]) + m(a[0], b[1]) + m(a[4], b2_19) + m(a[3], b3_19) + m(a[2], b4_19);
+        let c2 = m(a[2], b[0]) + m(a[1], b[1]) + m(a[0], b[2]) + m(a[4], b3_19) + m(a[3], b4_19);
+        let c3 = m(a[3], b[0]) + m(a[2], b[1]) + m(a[1], b[2]) + m(a[0], b[3]) + m(a[4], b4_19);
+        let c4 = m(a[4], b[0]) + m(a[3], b[1]) + m(a[2], b[2]) + m(a[1], b[3]) + m(a[0], b[4]);
+
+        Self::carry_wide([c0, c1, c2, c3, c4])
+    }
+
+    #[inline(always)]
+    pub(crate) fn square(&self) -> Self {
+        self.mul(self)
+    }
+
+    // computes self^(2^k)
+    pub(crate) fn square_n(&self, k: u32) -> Self {
+        let mut out = *self;
+        for _ in 0..k {
+            out = out.square();
+        }
+
+        out
+    }
+
+    #[inline(always)]
+    pub(crate) fn mul_small(&self, rhs: u32) -> Self {
+        let mut c = [0_u128; 5];
+        for (i, c) in c.iter_mut().enumerate() {
+            *c = u128::from(self.0[i]) * u128::from(rhs);
+        }
+
+        Self::carry_wide(c)
+    }
+
+    #[inline(always)]
+    fn carry_wide(mut c: [u128; 5]) -> Self {
+        let mask = u128::from(LIMB_MASK);
+
+        c[1] += c[0] >> LIMB_BITS;
+        c[2] += c[1] >> LIMB_BITS;
+        c[3] += c[2] >> LIMB_BITS;
+        c[4] += c[3] >> LIMB_BITS;
+
+        let carry = (c[4] >> LIMB_BITS) as u64;
+        let mut limbs = [
+            (c[0] & mask) as u64,
+            (c[1] & mask) as u64,
+            (c[2] & mask) as u64,
+            (c[3] & mask) as u64,
+            (c[4] & mask) as u64,
+        ];
+
+        limbs[0] += carry * 19;
+        limbs[1] += limbs[0] >> LIMB_BITS;
+        limbs[0] &= LIMB_MASK;
+
+        Self(limbs)
+    }
+
+    // returns (self^(2^250 - 1), self^11), shared by `invert` and `pow_p58`
+    fn pow22501(&self) -> (Self, Self) {
+        let t0 = self.square(); // 2
+        let t1 = t0.square().square(); // 8
+        let t2 = self.mul(&t1); // 9
+        let t3 = t0.mul(&t2); // 11
+        let t4 = t3.square(); // 22
+        let t5 = t2.mul(&t4); // 2^5 - 1
+        let t6 = t5.square_n(5); // 2^10 - 2^5
+        let t7 = t6.mul(&t5); // 2^10 - 1
+        let t8 = t7.square_n(10); // 2^20 - 2^10
+        let t9 = t8.mul(&t7); // 2^20 - 1
+        let t10 = t9.square_n(20); // 2^40 - 2^20
+        let t11 = t10.mul(&t9); // 2^40 - 1
+        let t12 = t11.square_n(10); // 2^50 - 2^10
+        let t13 = t12.mul(&t7); // 2^50 - 1
+        let t14 = t13.square_n(50); // 2^100 - 2^50
+        let t15 = t14.mul(&t13); // 2^100 - 1
+        let t16 = t15.square_n(100); // 2^200 - 2^100
+        let t17 = t16.mul(&t15); // 2^200 - 1
+        let t18 = t17.square_n(50); // 2^250 - 2^50
+        let t19 = t18.mul(&t13); // 2^250 - 1
+
+        (t19, t3)
+    }
+
+    // self^(p - 2) = self^-1, with 0^-1 = 0
+    pub(crate) fn invert(&self) -> Self {
+        let (t19, t3) = self.pow22501();
+
+        // 2^255 - 2^5 + 11 = 2^255 - 21
+        t19.square_n(5).mul(&t3)
+    }
+
+    // self^((p - 5) / 8) = self^(2^252 - 3)
+    pub(crate) fn pow_p58(&self) -> Self {
+        let (t19, _) = self.pow22501();
+
+        t19.square_n(2).mul(self)
+    }
+
+    // returns (true, +sqrt(u / v)) if u / v is square, (true, 0) if u is zero,
+    // (false, 0) if v is zero and (false, +sqrt(i * u / v)) otherwise, see
+    // RFC 9496 section 4.2
+    pub(crate) fn sqrt_ratio_i(u: &Self, v: &Self) -> (bool, Self) {
+        let v3 = v.square().mul(v);
+        let v7 = v3.square().mul(v);
+        let mut r = u.mul(&v3).mul(&u.mul(&v7).pow_p58());
+        let check = v.mul(&r.square());
+
+        let u_neg = u.neg();
+        let correct_sign = check.ct_eq(u);
+        let flipped_sign = check.ct_eq(&u_neg);
+        let flipped_sign_i = check.ct_eq(&u_neg.mul(&SQRT_M1));
+
+        let r_prime = r.mul(&SQRT_M1);
+        r.conditional_assign(&r_prime, flipped_sign | flipped_sign_i);
+
+        // choose the non-negative root
+        let r_is_negative = r.is_negative();
+        r.conditional_negate(r_is_negative);
+
+        (correct_sign | flipped_sign, r)
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        let bytes = self.to_bytes();
+        let acc = bytes.iter().fold(0_u8, |acc, b| acc | b);
+
+        acc == 0
+    }
+
+    pub(crate) fn is_negative(&self) -> bool {
+        self.to_bytes()[0] & 1 == 1
+    }
+
+    pub(crate) fn ct_eq(&self, rhs: &Self) -> bool {
+        let a = self.to_bytes();
+        let b = rhs.to_bytes();
+        let acc = a.iter().zip(&b).fold(0_u8, |acc, (a, b)| acc | (a ^ b));
+
+        acc == 0
+    }
+
+    // sets self to rhs when choice is true, without branching on choice
+    #[inline(always)]
+    pub(crate) fn conditional_assign(&mut self, rhs: &Self, choice: bool) {
+        let mask = 0_u64.wrapping_sub(choice as u64);
+        for i in 0..5 {
+            self.0[i] ^= mask & (self.0[i] ^ rhs.0[i]);
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn conditional_swap(a: &mut Self, b: &mut Self, choice: bool) {
+        let mask = 0_u64.wrapping_sub(choice as u64);
+        for i in 0..5 {
+            let t = mask & (a.0[i] ^ b.0[i]);
+            a.0[i] ^= t;
+            b.0[i] ^= t;
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn conditional_negate(&mut self, choice: bool) {
+        let negated = self.neg();
+        self.conditional_assign(&negated, choice);
+    }
+}
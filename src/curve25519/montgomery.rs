@@ -0,0 +1,45 @@
+use super::FieldElement;
+
+// (A - 2) / 4 for curve25519's A = 486662
+const A24: u32 = 121665;
+
+// Montgomery ladder from RFC 7748 section 5, the scalar is used as given and
+// callers are responsible for clamping
+pub(crate) fn mul(scalar: &[u8; 32], u: &[u8; 32]) -> [u8; 32] {
+    let x1 = FieldElement::from_bytes(u);
+
+    let mut x2 = FieldElement::ONE;
+    let mut z2 = FieldElement::ZERO;
+    let mut x3 = x1;
+    let mut z3 = FieldElement::ONE;
+    let mut swap = false;
+
+    for t in (0..255).rev() {
+        let bit = (scalar[t / 8] >> (t % 8)) & 1 == 1;
+
+        swap ^= bit;
+        FieldElement::conditional_swap(&mut x2, &mut x3, swap);
+        FieldElement::conditional_swap(&mut z2, &mut z3, swap);
+        swap = bit;
+
+        let a = x2.add(&z2);
+        let aa = a.square();
+        let b = x2.sub(&z2);
+        let bb = b.square();
+        let e = aa.sub(&bb);
+        let c = x3.add(&z3);
+        let d = x3.sub(&z3);
+        let da = d.mul(&a);
+        let cb = c.mul(&b);
+
+        x3 = da.add(&cb).square();
+        z3 = x1.mul(&da.sub(&cb).square());
+        x2 = aa.mul(&bb);
+        z2 = e.mul(&aa.add(&e.mul_small(A24)));
+    }
+
+    FieldElement::conditional_swap(&mut x2, &mut x3, swap);
+    FieldElement::conditional_swap(&mut z2, &mut z3, swap);
+
+    x2.mul(&z2.invert()).to_bytes()
+}
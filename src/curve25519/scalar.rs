@@ -0,0 +1,349 @@
+use std::fmt;
+
+#[cfg(feature = "rand")]
+use rand_core::CryptoRng;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use crate::ct::ConstantTimeEq;
+
+const LIMB_BITS: u32 = 52;
+const LIMB_MASK: u64 = (1 << LIMB_BITS) - 1;
+
+// l = 2^252 + 27742317777372353535851937790883648493 in radix 2^52
+const L: Scalar52 = Scalar52([
+    0x2631a5cf5d3ed,
+    0xdea2f79cd6581,
+    0x14def9,
+    0x0,
+    0x100000000000,
+]);
+
+// -l^-1 mod 2^52
+const L_FACTOR: u64 = 0x51da312547e1b;
+
+// R = 2^260 mod l
+const R: Scalar52 = Scalar52([
+    0xf48bd6721e6ed,
+    0x3bab5ac67e45a,
+    0xfffffeb35e51b,
+    0xfffffffffffff,
+    0xfffffffffff,
+]);
+
+// R^2 = 2^520 mod l
+const RR: Scalar52 = Scalar52([
+    0x9d265e952d13b,
+    0xd63c715bea69f,
+    0x5be65cb687604,
+    0x3dceec73d217f,
+    0x9411b7c309a,
+]);
+
+// l - 2, the exponent used for inversion
+const L_MINUS_2: [u8; 32] = [
+    0xeb, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+];
+
+// An integer modulo the edwards25519 group order l, always stored in its
+// canonical little-endian encoding.
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize))]
+pub struct Scalar([u8; 32]);
+
+impl Scalar {
+    pub const ZERO: Self = Self([0; 32]);
+    pub const ONE: Self = {
+        let mut bytes = [0; 32];
+        bytes[0] = 1;
+        Self(bytes)
+    };
+
+    pub fn from_u64(value: u64) -> Self {
+        let mut bytes = [0; 32];
+        bytes[..8].copy_from_slice(&value.to_le_bytes());
+
+        Self(bytes)
+    }
+
+    pub const fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    pub fn from_bytes_mod_order(bytes: &[u8; 32]) -> Self {
+        let mut wide = [0_u8; 64];
+        wide[..32].copy_from_slice(bytes);
+
+        Self::from_bytes_mod_order_wide(&wide)
+    }
+
+    pub fn from_bytes_mod_order_wide(bytes: &[u8; 64]) -> Self {
+        Self(Scalar52::from_bytes_wide(bytes).to_bytes())
+    }
+
+    #[cfg(feature = "rand")]
+    pub fn random_from_rng<R: CryptoRng + ?Sized>(rng: &mut R) -> Self {
+        let mut wide = [0_u8; 64];
+        rng.fill_bytes(&mut wide);
+
+        Self::from_bytes_mod_order_wide(&wide)
+    }
+
+    pub fn from_canonical_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        let reduced = Self::from_bytes_mod_order(bytes);
+
+        match &reduced.0 == bytes {
+            true => Some(reduced),
+            false => None,
+        }
+    }
+
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    pub fn add(&self, rhs: &Self) -> Self {
+        Self(Scalar52::add(&self.unpack(), &rhs.unpack()).to_bytes())
+    }
+
+    pub fn sub(&self, rhs: &Self) -> Self {
+        Self(Scalar52::sub(&self.unpack(), &rhs.unpack()).to_bytes())
+    }
+
+    pub fn mul(&self, rhs: &Self) -> Self {
+        Self(Scalar52::mul(&self.unpack(), &rhs.unpack()).to_bytes())
+    }
+
+    pub fn neg(&self) -> Self {
+        Self::ZERO.sub(self)
+    }
+
+    // self^(l - 2) = self^-1, with 0^-1 = 0
+    pub fn invert(&self) -> Self {
+        let base = self.unpack();
+        let mut acc = Scalar52::from_bytes(&Self::ONE.0);
+
+        for i in (0..256).rev() {
+            acc = Scalar52::mul(&acc, &acc);
+
+            if (L_MINUS_2[i / 8] >> (i % 8)) & 1 == 1 {
+                acc = Scalar52::mul(&acc, &base);
+            }
+        }
+
+        Self(acc.to_bytes())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.iter().fold(0_u8, |acc, b| acc | b) == 0
+    }
+
+    #[inline(always)]
+    fn unpack(&self) -> Scalar52 {
+        Scalar52::from_bytes(&self.0)
+    }
+}
+
+impl ConstantTimeEq for Scalar {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> bool {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+// scalars are often secret, so equality is constant-time and Debug prints
+// nothing of the value
+impl PartialEq for Scalar {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other)
+    }
+}
+
+impl Eq for Scalar {}
+
+impl fmt::Debug for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Scalar(<redacted>)")
+    }
+}
+
+// Unpacked scalar in radix 2^52 used for the Montgomery arithmetic.
+#[derive(Clone, Copy)]
+struct Scalar52([u64; 5]);
+
+impl Scalar52 {
+    fn from_bytes(bytes: &[u8; 32]) -> Self {
+        let mut words = [0_u64; 4];
+        for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(8)) {
+            *word = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+
+        Self([
+            words[0] & LIMB_MASK,
+            ((words[0] >> 52) | (words[1] << 12)) & LIMB_MASK,
+            ((words[1] >> 40) | (words[2] << 24)) & LIMB_MASK,
+            ((words[2] >> 28) | (words[3] << 36)) & LIMB_MASK,
+            words[3] >> 16,
+        ])
+    }
+
+    fn from_bytes_wide(bytes: &[u8; 64]) -> Self {
+        let mut words = [0_u64; 8];
+        for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(8)) {
+            *word = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+
+        // split into the low 260 bits and the high 252 bits
+        let lo = Self([
+            words[0] & LIMB_MASK,
+            ((words[0] >> 52) | (words[1] << 12)) & LIMB_MASK,
+            ((words[1] >> 40) | (words[2] << 24)) & LIMB_MASK,
+            ((words[2] >> 28) | (words[3] << 36)) & LIMB_MASK,
+            ((words[3] >> 16) | (words[4] << 48)) & LIMB_MASK,
+        ]);
+        let hi = Self([
+            (words[4] >> 4) & LIMB_MASK,
+            ((words[4] >> 56) | (words[5] << 8)) & LIMB_MASK,
+            ((words[5] >> 44) | (words[6] << 20)) & LIMB_MASK,
+            ((words[6] >> 32) | (words[7] << 32)) & LIMB_MASK,
+            words[7] >> 20,
+        ]);
+
+        // (lo * R) / R = lo and (hi * R^2) / R = hi * 2^260, both reduced
+        let lo = Self::montgomery_mul(&lo, &R);
+        let hi = Self::montgomery_mul(&hi, &RR);
+
+        Self::add(&hi, &lo)
+    }
+
+    fn to_bytes(self) -> [u8; 32] {
+        let mut out = [0_u8; 32];
+        let mut acc = 0_u128;
+        let mut acc_bits = 0;
+        let mut pos = 0;
+
+        for limb in self.0 {
+            acc |= u128::from(limb) << acc_bits;
+            acc_bits += LIMB_BITS;
+
+            while acc_bits >= 8 && pos < out.len() {
+                out[pos] = acc as u8;
+                acc >>= 8;
+                acc_bits -= 8;
+                pos += 1;
+            }
+        }
+
+        out
+    }
+
+    // computes a + b mod l for reduced inputs
+    fn add(a: &Self, b: &Self) -> Self {
+        let mut sum = [0_u64; 5];
+        let mut carry = 0_u64;
+
+        for (i, limb) in sum.iter_mut().enumerate() {
+            carry = a.0[i] + b.0[i] + (carry >> LIMB_BITS);
+            *limb = carry & LIMB_MASK;
+        }
+
+        Self::sub(&Self(sum), &L)
+    }
+
+    // computes a - b mod l for reduced inputs, adding l back on underflow
+    fn sub(a: &Self, b: &Self) -> Self {
+        let mut diff = [0_u64; 5];
+        let mut borrow = 0_u64;
+
+        for (i, limb) in diff.iter_mut().enumerate() {
+            borrow = a.0[i].wrapping_sub(b.0[i] + (borrow >> 63));
+            *limb = borrow & LIMB_MASK;
+        }
+
+        let underflow_mask = ((borrow >> 63) ^ 1).wrapping_sub(1);
+        let mut carry = 0_u64;
+
+        for (i, limb) in diff.iter_mut().enumerate() {
+            carry = (carry >> LIMB_BITS) + *limb + (L.0[i] & underflow_mask);
+            *limb = carry & LIMB_MASK;
+        }
+
+        Self(diff)
+    }
+
+    fn mul(a: &Self, b: &Self) -> Self {
+        let ab = Self::montgomery_mul(a, b);
+
+        Self::montgomery_mul(&ab, &RR)
+    }
+
+    #[inline(always)]
+    fn mul_internal(a: &Self, b: &Self) -> [u128; 9] {
+        #[inline(always)]
+        fn m(a: u64, b: u64) -> u128 {
+            u128::from(a) * u128::from(b)
+        }
+
+        let a = &a.0;
+        let b = &b.0;
+
+        [
+            m(a[0], b[0]),
+            m(a[0], b[1]) + m(a[1], b[0]),
+            m(a[0], b[2]) + m(a[1], b[1]) + m(a[2], b[0]),
+            m(a[0], b[3]) + m(a[1], b[2]) + m(a[2], b[1]) + m(a[3], b[0]),
+            m(a[0], b[4]) + m(a[1], b[3]) + m(a[2], b[2]) + m(a[3], b[1]) + m(a[4], b[0]),
+            m(a[1], b[4]) + m(a[2], b[3]) + m(a[3], b[2]) + m(a[4], b[1]),
+            m(a[2], b[4]) + m(a[3], b[3]) + m(a[4], b[2]),
+            m(a[3], b[4]) + m(a[4], b[3]),
+            m(a[4], b[4]),
+        ]
+    }
+
+    // computes a * b / R mod l
+    fn montgomery_mul(a: &Self, b: &Self) -> Self {
+        Self::montgomery_reduce(&Self::mul_internal(a, b))
+    }
+
+    // computes limbs / R mod l
+    #[inline(always)]
+    fn montgomery_reduce(limbs: &[u128; 9]) -> Self {
+        #[inline(always)]
+        fn m(a: u64, b: u64) -> u128 {
+            u128::from(a) * u128::from(b)
+        }
+
+        // pick n so that the low limb of sum + n * l is zero
+        #[inline(always)]
+        fn part1(sum: u128) -> (u128, u64) {
+            let n = (sum as u64).wrapping_mul(L_FACTOR) & LIMB_MASK;
+
+            ((sum + m(n, L.0[0])) >> LIMB_BITS, n)
+        }
+
+        #[inline(always)]
+        fn part2(sum: u128) -> (u128, u64) {
+            ((sum >> LIMB_BITS), (sum as u64) & LIMB_MASK)
+        }
+
+        let l = &L.0;
+
+        // l[3] is zero, so its products are skipped
+        let (carry, n0) = part1(limbs[0]);
+        let (carry, n1) = part1(carry + limbs[1] + m(n0, l[1]));
+        let (carry, n2) = part1(carry + limbs[2] + m(n0, l[2]) + m(n1, l[1]));
+        let (carry, n3) = part1(carry + limbs[3] + m(n1, l[2]) + m(n2, l[1]));
+        let (carry, n4) = part1(carry + limbs[4] + m(n0, l[4]) + m(n2, l[2]) + m(n3, l[1]));
+
+        let (carry, r0) = part2(carry + limbs[5] + m(n1, l[4]) + m(n3, l[2]) + m(n4, l[1]));
+        let (carry, r1) = part2(carry + limbs[6] + m(n2, l[4]) + m(n4, l[2]));
+        let (carry, r2) = part2(carry + limbs[7] + m(n3, l[4]));
+        let (carry, r3) = part2(carry + limbs[8] + m(n4, l[4]));
+        let r4 = carry as u64;
+
+        // the result is below 2l, so a single conditional subtraction suffices
+        Self::sub(&Self([r0, r1, r2, r3, r4]), &L)
+    }
+}
@@ -0,0 +1,431 @@
+use std::fmt;
+
+#[cfg(feature = "rand")]
+use rand_core::CryptoRng;
+
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::chacha::{ChaCha20Rng, Seed};
+use crate::curve25519::{EdwardsPoint, Scalar};
+use crate::sha2::Sha512;
+
+pub const SECRET_KEY_LEN: usize = 32;
+pub const PUBLIC_KEY_LEN: usize = 32;
+pub const SIGNATURE_LEN: usize = 64;
+
+pub const MAX_CONTEXT_LEN: usize = 255;
+
+const DOM2_PREFIX: &[u8] = b"SigEd25519 no Ed25519 collisions";
+const BATCH_DOMAIN: &[u8] = b"purecrypt ed25519 batch verification";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    InvalidSignature,
+    InvalidVerifyingKey,
+    InvalidContext,
+    BatchLengthMismatch,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::InvalidSignature => "ed25519 signature is invalid",
+            Self::InvalidVerifyingKey => "ed25519 verifying key is invalid",
+            Self::InvalidContext => "ed25519 context is empty or longer than 255 bytes",
+            Self::BatchLengthMismatch => "ed25519 batch inputs have different lengths",
+        };
+
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+// Strict follows RFC 8032 with canonical encodings, rejects small-order keys
+// and R values and checks the cofactorless equation. Zip215 follows the
+// ZIP-215 consensus rules: non-canonical point encodings are accepted and
+// the cofactored equation is checked. Both require a canonical S.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VerificationMode {
+    #[default]
+    Strict,
+    Zip215,
+}
+
+// Domain separation for the RFC 8032 variants, dom2(phflag, context) is
+// empty for plain Ed25519.
+#[derive(Clone, Copy)]
+enum Variant<'a> {
+    Pure,
+    Context(&'a [u8]),
+    Prehashed(&'a [u8]),
+}
+
+impl Variant<'_> {
+    fn validate(&self) -> Result<(), Error> {
+        match self {
+            Self::Pure => Ok(()),
+            Self::Context([]) => Err(Error::InvalidContext),
+            Self::Context(context) | Self::Prehashed(context) => {
+                match context.len() <= MAX_CONTEXT_LEN {
+                    true => Ok(()),
+                    false => Err(Error::InvalidContext),
+                }
+            }
+        }
+    }
+
+    fn hasher(&self) -> Sha512 {
+        let mut hasher = Sha512::new();
+
+        let (flag, context) = match self {
+            Self::Pure => return hasher,
+            Self::Context(context) => (0, context),
+            Self::Prehashed(context) => (1, context),
+        };
+
+        hasher.update(DOM2_PREFIX);
+        hasher.update(&[flag, context.len() as u8]);
+        hasher.update(context);
+
+        hasher
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Signature([u8; SIGNATURE_LEN]);
+
+impl Signature {
+    pub fn r_bytes(&self) -> &[u8; 32] {
+        self.0[..32].try_into().unwrap()
+    }
+
+    pub fn s_bytes(&self) -> &[u8; 32] {
+        self.0[32..].try_into().unwrap()
+    }
+
+    fn from_parts(r: &[u8; 32], s: &Scalar) -> Self {
+        let mut bytes = [0; SIGNATURE_LEN];
+        bytes[..32].copy_from_slice(r);
+        bytes[32..].copy_from_slice(s.as_bytes());
+
+        Self(bytes)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct VerifyingKey {
+    compressed: [u8; PUBLIC_KEY_LEN],
+    point: EdwardsPoint,
+}
+
+impl VerifyingKey {
+    pub fn from_bytes(bytes: &[u8; PUBLIC_KEY_LEN]) -> Result<Self, Error> {
+        let point = EdwardsPoint::decompress(bytes).ok_or(Error::InvalidVerifyingKey)?;
+
+        Ok(Self {
+            compressed: *bytes,
+            point,
+        })
+    }
+
+    pub const fn to_bytes(&self) -> [u8; PUBLIC_KEY_LEN] {
+        self.compressed
+    }
+
+    pub const fn as_bytes(&self) -> &[u8; PUBLIC_KEY_LEN] {
+        &self.compressed
+    }
+
+    // weak keys are small-order points, a signature under them can be valid
+    // for many messages
+    pub fn is_weak(&self) -> bool {
+        self.point.is_small_order()
+    }
+
+    #[inline]
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> Result<(), Error> {
+        self.verify_with_mode(message, signature, VerificationMode::Strict)
+    }
+
+    #[inline]
+    pub fn verify_with_mode(
+        &self,
+        message: &[u8],
+        signature: &Signature,
+        mode: VerificationMode,
+    ) -> Result<(), Error> {
+        self.verify_inner(Variant::Pure, message, signature, mode)
+    }
+
+    pub fn verify_with_context(
+        &self,
+        message: &[u8],
+        context: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        self.verify_inner(
+            Variant::Context(context),
+            message,
+            signature,
+            VerificationMode::Strict,
+        )
+    }
+
+    pub fn verify_prehashed(
+        &self,
+        prehashed: Sha512,
+        context: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        self.verify_inner(
+            Variant::Prehashed(context),
+            &prehashed.finalize(),
+            signature,
+            VerificationMode::Strict,
+        )
+    }
+
+    fn verify_inner(
+        &self,
+        variant: Variant<'_>,
+        message: &[u8],
+        signature: &Signature,
+        mode: VerificationMode,
+    ) -> Result<(), Error> {
+        variant.validate()?;
+
+        let s = Scalar::from_canonical_bytes(signature.s_bytes()).ok_or(Error::InvalidSignature)?;
+        let r = EdwardsPoint::decompress(signature.r_bytes()).ok_or(Error::InvalidSignature)?;
+
+        if mode == VerificationMode::Strict {
+            if self.point.compress() != self.compressed || self.is_weak() {
+                return Err(Error::InvalidVerifyingKey);
+            }
+
+            if r.compress() != *signature.r_bytes() || r.is_small_order() {
+                return Err(Error::InvalidSignature);
+            }
+        }
+
+        let k = challenge(variant, signature.r_bytes(), &self.compressed, message);
+
+        // [S]B - [k]A
+        let sb_minus_ka = EdwardsPoint::mul_base(&s).sub(&self.point.mul(&k));
+
+        let valid = match mode {
+            VerificationMode::Strict => sb_minus_ka.compress() == *signature.r_bytes(),
+            VerificationMode::Zip215 => sb_minus_ka.sub(&r).mul_by_cofactor().is_identity(),
+        };
+
+        match valid {
+            true => Ok(()),
+            false => Err(Error::InvalidSignature),
+        }
+    }
+}
+
+impl PartialEq for VerifyingKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.compressed == other.compressed
+    }
+}
+
+impl Eq for VerifyingKey {}
+
+impl TryFrom<&[u8; PUBLIC_KEY_LEN]> for VerifyingKey {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8; PUBLIC_KEY_LEN]) -> Result<Self, Self::Error> {
+        Self::from_bytes(bytes)
+    }
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub struct SigningKey {
+    secret: [u8; SECRET_KEY_LEN],
+    scalar: Scalar,
+    prefix: [u8; 32],
+    #[cfg_attr(feature = "zeroize", zeroize(skip))]
+    verifying_key: VerifyingKey,
+}
+
+impl SigningKey {
+    pub fn from_bytes(secret: &[u8; SECRET_KEY_LEN]) -> Self {
+        let hash = Sha512::digest(secret);
+
+        let mut scalar_bytes: [u8; 32] = hash[..32].try_into().unwrap();
+        scalar_bytes[0] &= 248;
+        scalar_bytes[31] &= 127;
+        scalar_bytes[31] |= 64;
+
+        // the clamped scalar is below 2^255, the point multiplication uses
+        // it as is while signing uses it modulo l
+        let point = EdwardsPoint::basepoint().mul_bytes(&scalar_bytes);
+        let scalar = Scalar::from_bytes_mod_order(&scalar_bytes);
+
+        #[cfg(feature = "zeroize")]
+        scalar_bytes.zeroize();
+
+        Self {
+            secret: *secret,
+            scalar,
+            prefix: hash[32..].try_into().unwrap(),
+            verifying_key: VerifyingKey {
+                compressed: point.compress(),
+                point,
+            },
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    pub fn random_from_rng<R: CryptoRng + ?Sized>(rng: &mut R) -> Self {
+        let mut secret = [0; SECRET_KEY_LEN];
+        rng.fill_bytes(&mut secret);
+
+        let signing_key = Self::from_bytes(&secret);
+
+        #[cfg(feature = "zeroize")]
+        secret.zeroize();
+
+        signing_key
+    }
+
+    pub const fn to_bytes(&self) -> [u8; SECRET_KEY_LEN] {
+        self.secret
+    }
+
+    pub const fn as_bytes(&self) -> &[u8; SECRET_KEY_LEN] {
+        &self.secret
+    }
+
+    pub const fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+
+    pub fn sign(&self, message: &[u8]) -> Signature {
+        self.sign_inner(Variant::Pure, message)
+    }
+
+    pub fn sign_with_context(&self, message: &[u8], context: &[u8]) -> Result<Signature, Error> {
+        let variant = Variant::Context(context);
+        variant.validate()?;
+
+        Ok(self.sign_inner(variant, message))
+    }
+
+    pub fn sign_prehashed(&self, prehashed: Sha512, context: &[u8]) -> Result<Signature, Error> {
+        let variant = Variant::Prehashed(context);
+        variant.validate()?;
+
+        Ok(self.sign_inner(variant, &prehashed.finalize()))
+    }
+
+    fn sign_inner(&self, variant: Variant<'_>, message: &[u8]) -> Signature {
+        // r = SHA-512(dom2(F, C) || prefix || PH(M))
+        let mut hasher = variant.hasher();
+        hasher.update(&self.prefix);
+        hasher.update(message);
+        let r = Scalar::from_bytes_mod_order_wide(&hasher.finalize());
+
+        let big_r = EdwardsPoint::mul_base(&r).compress();
+        let k = challenge(variant, &big_r, &self.verifying_key.compressed, message);
+
+        // S = r + k * s mod l
+        let s = r.add(&k.mul(&self.scalar));
+
+        Signature::from_parts(&big_r, &s)
+    }
+}
+
+impl From<&[u8; SECRET_KEY_LEN]> for SigningKey {
+    fn from(secret: &[u8; SECRET_KEY_LEN]) -> Self {
+        Self::from_bytes(secret)
+    }
+}
+
+// k = SHA-512(dom2(F, C) || R || A || PH(M)) mod l
+fn challenge(variant: Variant<'_>, r: &[u8; 32], a: &[u8; 32], message: &[u8]) -> Scalar {
+    let mut hasher = variant.hasher();
+    hasher.update(r);
+    hasher.update(a);
+    hasher.update(message);
+
+    Scalar::from_bytes_mod_order_wide(&hasher.finalize())
+}
+
+// Verifies plain Ed25519 signatures together by checking a random linear
+// combination of the cofactored equations, so it accepts exactly the
+// signatures `VerificationMode::Zip215` accepts. The 128-bit coefficients are
+// drawn from a ChaCha20Rng seeded with a hash of the whole batch, and the
+// combination is a single multiscalar multiplication over every R_i, A_i and
+// the base point.
+pub fn verify_batch(
+    messages: &[&[u8]],
+    signatures: &[Signature],
+    verifying_keys: &[VerifyingKey],
+) -> Result<(), Error> {
+    if messages.len() != signatures.len() || messages.len() != verifying_keys.len() {
+        return Err(Error::BatchLengthMismatch);
+    }
+
+    let mut s_values = Vec::with_capacity(signatures.len());
+    let mut r_points = Vec::with_capacity(signatures.len());
+    let mut challenges = Vec::with_capacity(signatures.len());
+    let mut transcript = Sha512::new();
+    transcript.update(BATCH_DOMAIN);
+
+    for ((message, signature), key) in messages.iter().zip(signatures).zip(verifying_keys) {
+        let s = Scalar::from_canonical_bytes(signature.s_bytes()).ok_or(Error::InvalidSignature)?;
+        let r = EdwardsPoint::decompress(signature.r_bytes()).ok_or(Error::InvalidSignature)?;
+        let k = challenge(Variant::Pure, signature.r_bytes(), key.as_bytes(), message);
+
+        transcript.update(&signature.0);
+        transcript.update(key.as_bytes());
+        transcript.update(k.as_bytes());
+
+        s_values.push(s);
+        r_points.push(r);
+        challenges.push(k);
+    }
+
+    let digest = transcript.finalize();
+    let mut rng = ChaCha20Rng::from_seed(&Seed::new(digest[..32].try_into().unwrap()));
+
+    // sum(z_i * R_i) + sum(z_i * k_i * A_i) - (sum(z_i * s_i)) * B
+    let mut s_sum = Scalar::ZERO;
+    let mut scalars = Vec::with_capacity(2 * signatures.len() + 1);
+    let mut points = Vec::with_capacity(2 * signatures.len() + 1);
+
+    for ((s, r), (k, key)) in s_values
+        .iter()
+        .zip(r_points)
+        .zip(challenges.iter().zip(verifying_keys))
+    {
+        let mut z_bytes = [0; 32];
+        rng.fill_bytes(&mut z_bytes[..16]);
+        let z = Scalar::from_bytes_mod_order(&z_bytes);
+
+        s_sum = s_sum.add(&z.mul(s));
+
+        scalars.push(z.mul(k));
+        points.push(key.point);
+        scalars.push(z);
+        points.push(r);
+    }
+
+    scalars.push(s_sum.neg());
+    points.push(EdwardsPoint::basepoint());
+
+    let acc = EdwardsPoint::vartime_multiscalar_mul(&scalars, &points);
+
+    match acc.mul_by_cofactor().is_identity() {
+        true => Ok(()),
+        false => Err(Error::InvalidSignature),
+    }
+}
+
+bytes_wrapper_impl!(Signature, SIGNATURE_LEN, public);
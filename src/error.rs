@@ -0,0 +1,23 @@
+use std::fmt;
+
+// errors raised while building values from untrusted input, shared by the
+// whole crate; primitives with richer failure modes keep their own enums
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    InvalidLength { expected: usize, actual: usize },
+    // text that is not valid hex or base64 for its alphabet and padding
+    InvalidEncoding,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength { expected, actual } => {
+                write!(f, "invalid length: expected {expected} bytes, got {actual}")
+            }
+            Self::InvalidEncoding => f.write_str("invalid hex or base64 encoding"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
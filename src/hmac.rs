@@ -0,0 +1,61 @@
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::sha2::Sha256;
+use crate::sha2::sha256::{BLOCK_LEN, OUTPUT_LEN};
+
+const IPAD: u8 = 0x36;
+const OPAD: u8 = 0x5c;
+
+#[derive(Clone)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub struct HmacSha256 {
+    inner: Sha256,
+    outer: Sha256,
+}
+
+impl HmacSha256 {
+    pub fn new(key: &[u8]) -> Self {
+        let mut block = [0_u8; BLOCK_LEN];
+
+        // keys longer than a block are hashed first
+        match key.len() > BLOCK_LEN {
+            true => block[..OUTPUT_LEN].copy_from_slice(&Sha256::digest(key)),
+            false => block[..key.len()].copy_from_slice(key),
+        }
+
+        let mut inner = Sha256::new();
+        block.iter_mut().for_each(|b| *b ^= IPAD);
+        inner.update(&block);
+
+        let mut outer = Sha256::new();
+        block.iter_mut().for_each(|b| *b ^= IPAD ^ OPAD);
+        outer.update(&block);
+
+        #[cfg(feature = "zeroize")]
+        block.zeroize();
+
+        Self { inner, outer }
+    }
+
+    pub fn mac(key: &[u8], data: &[u8]) -> [u8; OUTPUT_LEN] {
+        let mut hmac = Self::new(key);
+        hmac.update(data);
+
+        hmac.finalize()
+    }
+
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    pub fn finalize(self) -> [u8; OUTPUT_LEN] {
+        let inner = self.inner.clone().finalize();
+
+        let mut outer = self.outer.clone();
+        outer.update(&inner);
+
+        outer.finalize()
+    }
+}
@@ -1,3 +1,25 @@
+pub use self::error::Error;
+
 #[macro_use]
 pub mod utils;
+pub mod aead;
+pub mod aes;
+pub mod aes_gcm;
+pub mod aes_gcm_siv;
 pub mod chacha;
+pub mod chacha20_poly1305_siv;
+pub mod ct;
+pub mod ed25519;
+pub mod hmac;
+pub mod os;
+pub mod pbkdf2;
+pub mod poly1305;
+pub mod ristretto255;
+pub mod salsa;
+pub mod scrypt;
+pub mod sha2;
+pub mod x25519;
+
+mod curve25519;
+mod error;
+mod polyval;
@@ -1,10 +1,16 @@
+use std::hint::black_box;
+use std::time::Instant;
+
 use purecrypt::chacha::ChaCha20Rng;
 
+// draws per small-draw workload
+const DRAWS: usize = 1 << 26;
+
 fn main() {
     let mut buf = vec![0_u8; 1024 * 1024 * 1024];
-    let mut rng = ChaCha20Rng::from_seed(&Default::default());
+    let mut rng = ChaCha20Rng::from_entropy().expect("os entropy is unavailable");
 
-    let now = std::time::Instant::now();
+    let now = Instant::now();
 
     rng.fill_bytes(&mut buf);
 
@@ -15,4 +21,30 @@ fn main() {
         &buf[0..8],
         &buf[buf.len() - 8..buf.len()]
     );
+
+    bench("next_u32", 4, || black_box(rng.next_u32()) as u64);
+    bench("next_u64", 8, || black_box(rng.next_u64()));
+
+    for len in [1, 3, 16] {
+        let mut small = [0_u8; 16];
+        bench(&format!("fill_bytes({len})"), len, || {
+            rng.fill_bytes(&mut small[..len]);
+            black_box(small[0]) as u64
+        });
+    }
+}
+
+fn bench(name: &str, bytes_per_draw: usize, mut draw: impl FnMut() -> u64) {
+    let now = Instant::now();
+
+    let mut acc = 0_u64;
+    for _ in 0..DRAWS {
+        acc ^= draw();
+    }
+
+    let elapsed = now.elapsed();
+    let per_draw = elapsed / DRAWS as u32;
+    let mib_per_sec = (DRAWS * bytes_per_draw) as f64 / elapsed.as_secs_f64() / (1 << 20) as f64;
+
+    println!("{name}: {per_draw:?} per draw, {mib_per_sec:.1} MiB/s (acc {acc:x})");
 }
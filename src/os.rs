@@ -0,0 +1,126 @@
+use std::fmt;
+
+use crate::chacha::ChaCha20Rng;
+
+// getrandom(2) by architecture, other targets read /dev/urandom only
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+const SYS_GETRANDOM: i64 = 318;
+#[cfg(all(target_os = "linux", target_arch = "x86"))]
+const SYS_GETRANDOM: i64 = 355;
+#[cfg(all(target_os = "linux", target_arch = "aarch64"))]
+const SYS_GETRANDOM: i64 = 278;
+#[cfg(all(target_os = "linux", target_arch = "riscv64"))]
+const SYS_GETRANDOM: i64 = 278;
+#[cfg(all(target_os = "linux", target_arch = "arm"))]
+const SYS_GETRANDOM: i64 = 384;
+
+#[cfg(target_os = "linux")]
+const EINTR: i32 = 4;
+#[cfg(target_os = "linux")]
+const EPERM: i32 = 1;
+#[cfg(target_os = "linux")]
+const ENOSYS: i32 = 38;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    // the entropy source failed with this errno
+    Os(i32),
+    // the target has no entropy source this module knows about
+    Unsupported,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Os(code) => write!(f, "os entropy source failed with error {code}"),
+            Self::Unsupported => f.write_str("no os entropy source on this platform"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+// Fills `dst` from the kernel's cryptographic random source. On Linux this is
+// the getrandom syscall, which blocks only until the pool is first seeded;
+// kernels without it, or sandboxes that forbid it, fall back to /dev/urandom.
+pub fn fill(dst: &mut [u8]) -> Result<(), Error> {
+    #[cfg(all(
+        target_os = "linux",
+        any(
+            target_arch = "x86_64",
+            target_arch = "x86",
+            target_arch = "aarch64",
+            target_arch = "riscv64",
+            target_arch = "arm"
+        )
+    ))]
+    match getrandom(dst) {
+        Err(Error::Os(ENOSYS | EPERM)) => {}
+        result => return result,
+    }
+
+    urandom(dst)
+}
+
+#[cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "arm"
+    )
+))]
+fn getrandom(mut dst: &mut [u8]) -> Result<(), Error> {
+    unsafe extern "C" {
+        // libc's generic syscall wrapper, std links it already
+        fn syscall(number: std::ffi::c_long, ...) -> std::ffi::c_long;
+    }
+
+    while !dst.is_empty() {
+        // SAFETY: the kernel writes at most `dst.len()` bytes into `dst`
+        let ret = unsafe { syscall(SYS_GETRANDOM as _, dst.as_mut_ptr(), dst.len(), 0_u32) };
+
+        match ret < 0 {
+            true => match std::io::Error::last_os_error().raw_os_error() {
+                Some(EINTR) => continue,
+                Some(code) => return Err(Error::Os(code)),
+                None => return Err(Error::Unsupported),
+            },
+            false => dst = &mut dst[ret as usize..],
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(unix)]
+fn urandom(dst: &mut [u8]) -> Result<(), Error> {
+    use std::io::Read;
+
+    let result = std::fs::File::open("/dev/urandom").and_then(|mut file| file.read_exact(dst));
+
+    result.map_err(|error| match error.raw_os_error() {
+        Some(code) => Error::Os(code),
+        None => Error::Unsupported,
+    })
+}
+
+#[cfg(not(unix))]
+fn urandom(_dst: &mut [u8]) -> Result<(), Error> {
+    Err(Error::Unsupported)
+}
+
+// key and nonce material without an external rng: a ChaCha20Rng keyed from
+// the OS, dropped as soon as `dst` is filled
+pub(crate) fn try_fill_random(dst: &mut [u8]) -> Result<(), Error> {
+    ChaCha20Rng::from_entropy()?.fill_bytes(dst);
+
+    Ok(())
+}
+
+// for the `random` constructors, which treat missing entropy as fatal
+pub(crate) fn fill_random(dst: &mut [u8]) {
+    try_fill_random(dst).expect("os entropy is unavailable");
+}
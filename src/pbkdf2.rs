@@ -0,0 +1,33 @@
+use crate::hmac::HmacSha256;
+use crate::sha2::sha256::OUTPUT_LEN;
+
+pub fn pbkdf2_hmac_sha256(password: &[u8], salt: &[u8], rounds: u32, dst: &mut [u8]) {
+    assert!(rounds > 0, "pbkdf2 requires at least one round");
+    assert!(
+        (dst.len() as u64) <= u64::from(u32::MAX) * OUTPUT_LEN as u64,
+        "pbkdf2 output is too long"
+    );
+
+    let prf = HmacSha256::new(password);
+
+    for (i, chunk) in dst.chunks_mut(OUTPUT_LEN).enumerate() {
+        let block_index = (i as u32 + 1).to_be_bytes();
+
+        // U_1 = PRF(P, S || INT(i))
+        let mut hmac = prf.clone();
+        hmac.update(salt);
+        hmac.update(&block_index);
+        let mut u = hmac.finalize();
+        let mut t = u;
+
+        // U_j = PRF(P, U_{j-1}), T_i = U_1 ^ ... ^ U_c
+        for _ in 1..rounds {
+            let mut hmac = prf.clone();
+            hmac.update(&u);
+            u = hmac.finalize();
+            t.iter_mut().zip(&u).for_each(|(t, u)| *t ^= *u);
+        }
+
+        chunk.copy_from_slice(&t[..chunk.len()]);
+    }
+}
@@ -0,0 +1,199 @@
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+pub const KEY_LEN: usize = 32;
+pub const BLOCK_LEN: usize = 16;
+pub const TAG_LEN: usize = 16;
+
+const MASK44: u64 = (1 << 44) - 1;
+const MASK42: u64 = (1 << 42) - 1;
+
+// Poly1305 from RFC 8439 section 2.5, a one-time authenticator: a key must
+// never be used for more than one message. The accumulator is kept in three
+// limbs of 44, 44 and 42 bits so products fit into u128.
+#[derive(Clone)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub struct Poly1305 {
+    r: [u64; 3],
+    s: [u64; 2],
+    h: [u64; 3],
+    buffer: [u8; BLOCK_LEN],
+    buffer_len: usize,
+}
+
+impl Poly1305 {
+    pub fn new(key: &[u8; KEY_LEN]) -> Self {
+        let t0 = u64::from_le_bytes(key[0..8].try_into().unwrap());
+        let t1 = u64::from_le_bytes(key[8..16].try_into().unwrap());
+
+        // r is clamped while it is split into limbs
+        let r = [
+            t0 & 0xffc0fffffff,
+            ((t0 >> 44) | (t1 << 20)) & 0xfffffc0ffff,
+            (t1 >> 24) & 0x00ffffffc0f,
+        ];
+
+        let s = [
+            u64::from_le_bytes(key[16..24].try_into().unwrap()),
+            u64::from_le_bytes(key[24..32].try_into().unwrap()),
+        ];
+
+        Self {
+            r,
+            s,
+            h: [0; 3],
+            buffer: [0; BLOCK_LEN],
+            buffer_len: 0,
+        }
+    }
+
+    pub fn mac(key: &[u8; KEY_LEN], data: &[u8]) -> [u8; TAG_LEN] {
+        let mut poly = Self::new(key);
+        poly.update(data);
+
+        poly.finalize()
+    }
+
+    pub fn update(&mut self, mut data: &[u8]) {
+        if self.buffer_len > 0 {
+            let take = data.len().min(BLOCK_LEN - self.buffer_len);
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&data[..take]);
+            self.buffer_len += take;
+            data = &data[take..];
+
+            if self.buffer_len < BLOCK_LEN {
+                return;
+            }
+
+            let block = self.buffer;
+            self.process_block(&block, 1 << 40);
+            self.buffer_len = 0;
+        }
+
+        let mut blocks = data.chunks_exact(BLOCK_LEN);
+        for block in &mut blocks {
+            self.process_block(block.try_into().unwrap(), 1 << 40);
+        }
+
+        let rest = blocks.remainder();
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.buffer_len = rest.len();
+    }
+
+    // absorbs `data` and zero-pads it to a block boundary, as the AEAD
+    // constructions of RFC 8439 section 2.8 do
+    pub fn update_padded(&mut self, data: &[u8]) {
+        self.update(data);
+
+        if self.buffer_len > 0 {
+            self.update(&[0; BLOCK_LEN][self.buffer_len..]);
+        }
+    }
+
+    pub fn finalize(mut self) -> [u8; TAG_LEN] {
+        if self.buffer_len > 0 {
+            // the partial block gets its 1 byte appended explicitly
+            let mut block = [0; BLOCK_LEN];
+            block[..self.buffer_len].copy_from_slice(&self.buffer[..self.buffer_len]);
+            block[self.buffer_len] = 1;
+            self.process_block(&block, 0);
+
+            #[cfg(feature = "zeroize")]
+            block.zeroize();
+        }
+
+        let [mut h0, mut h1, mut h2] = self.h;
+
+        // fully carry h
+        let mut c;
+        c = h1 >> 44;
+        h1 &= MASK44;
+        h2 += c;
+        c = h2 >> 42;
+        h2 &= MASK42;
+        h0 += c * 5;
+        c = h0 >> 44;
+        h0 &= MASK44;
+        h1 += c;
+        c = h1 >> 44;
+        h1 &= MASK44;
+        h2 += c;
+        c = h2 >> 42;
+        h2 &= MASK42;
+        h0 += c * 5;
+        c = h0 >> 44;
+        h0 &= MASK44;
+        h1 += c;
+
+        // g = h + 5 - 2^130, selected without branching when h >= p
+        let mut g0 = h0 + 5;
+        c = g0 >> 44;
+        g0 &= MASK44;
+        let mut g1 = h1 + c;
+        c = g1 >> 44;
+        g1 &= MASK44;
+        let g2 = (h2 + c).wrapping_sub(1 << 42);
+
+        let mask = (g2 >> 63).wrapping_sub(1);
+        h0 = (h0 & !mask) | (g0 & mask);
+        h1 = (h1 & !mask) | (g1 & mask);
+        h2 = (h2 & !mask) | (g2 & mask);
+
+        // h + s mod 2^128
+        let [s0, s1] = self.s;
+        h0 += s0 & MASK44;
+        c = h0 >> 44;
+        h0 &= MASK44;
+        h1 += (((s0 >> 44) | (s1 << 20)) & MASK44) + c;
+        c = h1 >> 44;
+        h1 &= MASK44;
+        h2 += ((s1 >> 24) & MASK42) + c;
+        h2 &= MASK42;
+
+        let mut tag = [0; TAG_LEN];
+        tag[..8].copy_from_slice(&(h0 | (h1 << 44)).to_le_bytes());
+        tag[8..].copy_from_slice(&((h1 >> 20) | (h2 << 24)).to_le_bytes());
+
+        tag
+    }
+
+    // h = (h + block + hibit * 2^128) * r mod 2^130 - 5, where `hibit` is
+    // 2^40 in the top limb for full blocks
+    #[inline(always)]
+    fn process_block(&mut self, block: &[u8; BLOCK_LEN], hibit: u64) {
+        let [r0, r1, r2] = self.r;
+        let [mut h0, mut h1, mut h2] = self.h;
+
+        // 2^130 = 5 mod p, and the top limb is 42 bits wide
+        let s1 = r1 * (5 << 2);
+        let s2 = r2 * (5 << 2);
+
+        let t0 = u64::from_le_bytes(block[0..8].try_into().unwrap());
+        let t1 = u64::from_le_bytes(block[8..16].try_into().unwrap());
+
+        h0 += t0 & MASK44;
+        h1 += ((t0 >> 44) | (t1 << 20)) & MASK44;
+        h2 += ((t1 >> 24) & MASK42) | hibit;
+
+        let mul = |a: u64, b: u64| u128::from(a) * u128::from(b);
+
+        let d0 = mul(h0, r0) + mul(h1, s2) + mul(h2, s1);
+        let mut d1 = mul(h0, r1) + mul(h1, r0) + mul(h2, s2);
+        let mut d2 = mul(h0, r2) + mul(h1, r1) + mul(h2, r0);
+
+        let mut c = (d0 >> 44) as u64;
+        h0 = d0 as u64 & MASK44;
+        d1 += u128::from(c);
+        c = (d1 >> 44) as u64;
+        h1 = d1 as u64 & MASK44;
+        d2 += u128::from(c);
+        c = (d2 >> 42) as u64;
+        h2 = d2 as u64 & MASK42;
+        h0 += c * 5;
+        c = h0 >> 44;
+        h0 &= MASK44;
+        h1 += c;
+
+        self.h = [h0, h1, h2];
+    }
+}
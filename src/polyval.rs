@@ -0,0 +1,80 @@
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[cfg(target_arch = "x86_64")]
+mod clmul;
+mod soft;
+
+pub const BLOCK_LEN: usize = 16;
+
+// blocks handed to a backend per call
+const BATCH_BLOCKS: usize = 8;
+
+// POLYVAL from RFC 8452 section 3: acc = (acc + X_i) * H * x^-128 in
+// GF(2^128) defined by x^128 + x^127 + x^126 + x^121 + 1, with blocks read
+// as little-endian integers. AES-GCM-SIV uses it directly, GHASH is derived
+// from it in `aes_gcm`.
+#[derive(Clone)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub(crate) struct Polyval {
+    h: u128,
+    acc: u128,
+    clmul: bool,
+}
+
+impl Polyval {
+    // selects the PCLMULQDQ backend when `detect` is set and the CPU has it
+    pub(crate) fn new(h: &[u8; BLOCK_LEN], detect: bool) -> Self {
+        #[cfg(target_arch = "x86_64")]
+        let clmul = detect && clmul::is_supported();
+        #[cfg(not(target_arch = "x86_64"))]
+        let clmul = {
+            let _ = detect;
+            false
+        };
+
+        Self {
+            h: u128::from_le_bytes(*h),
+            acc: 0,
+            clmul,
+        }
+    }
+
+    pub(crate) fn update_blocks(&mut self, blocks: &[[u8; BLOCK_LEN]]) {
+        #[cfg(target_arch = "x86_64")]
+        if self.clmul {
+            // SAFETY: `clmul` is only set after is_x86_feature_detected!("pclmulqdq")
+            self.acc = unsafe { clmul::update_blocks(self.h, self.acc, blocks) };
+            return;
+        }
+
+        self.acc = soft::update_blocks(self.h, self.acc, blocks);
+    }
+
+    pub(crate) fn update_padded(&mut self, data: &[u8]) {
+        self.update_padded_with(data, |_| {});
+    }
+
+    // absorbs `data`, zero-padding the last partial block and applying `map`
+    // to every block first
+    pub(crate) fn update_padded_with(&mut self, data: &[u8], map: impl Fn(&mut [u8; BLOCK_LEN])) {
+        for chunk in data.chunks(BATCH_BLOCKS * BLOCK_LEN) {
+            let mut blocks = [[0; BLOCK_LEN]; BATCH_BLOCKS];
+            let count = chunk.len().div_ceil(BLOCK_LEN);
+
+            for (block, bytes) in blocks.iter_mut().zip(chunk.chunks(BLOCK_LEN)) {
+                block[..bytes.len()].copy_from_slice(bytes);
+                map(block);
+            }
+
+            self.update_blocks(&blocks[..count]);
+
+            #[cfg(feature = "zeroize")]
+            blocks.zeroize();
+        }
+    }
+
+    pub(crate) fn finalize(self) -> [u8; BLOCK_LEN] {
+        self.acc.to_le_bytes()
+    }
+}
@@ -0,0 +1,66 @@
+use std::arch::x86_64::*;
+
+use super::BLOCK_LEN;
+
+pub(crate) fn is_supported() -> bool {
+    std::arch::is_x86_feature_detected!("pclmulqdq")
+}
+
+#[inline(always)]
+fn to_m128(x: u128) -> __m128i {
+    // SAFETY: the pointer is valid for 16 bytes and loadu has no alignment
+    // requirement
+    unsafe { _mm_loadu_si128(x.to_le_bytes().as_ptr().cast()) }
+}
+
+#[inline(always)]
+fn from_m128(x: __m128i) -> u128 {
+    let mut bytes = [0_u8; BLOCK_LEN];
+    // SAFETY: as in `to_m128`
+    unsafe { _mm_storeu_si128(bytes.as_mut_ptr().cast(), x) };
+
+    u128::from_le_bytes(bytes)
+}
+
+// callers must have checked that the CPU supports pclmulqdq
+#[target_feature(enable = "pclmulqdq")]
+pub(crate) unsafe fn update_blocks(h: u128, acc: u128, blocks: &[[u8; BLOCK_LEN]]) -> u128 {
+    let h = to_m128(h);
+    let mut acc = to_m128(acc);
+
+    for block in blocks {
+        // SAFETY: pclmulqdq is enabled for this function and sse2 is baseline
+        acc = unsafe { mul(_mm_xor_si128(acc, to_m128(u128::from_le_bytes(*block))), h) };
+    }
+
+    from_m128(acc)
+}
+
+// x * y * x^-128 with a schoolbook product and two folding steps by
+// 0xc2000000000000000000000000000001, as in the RFC 8452 reference code;
+// callers must have checked that the CPU supports pclmulqdq
+#[target_feature(enable = "pclmulqdq")]
+// the intrinsics only became safe to call here in Rust 1.87
+#[allow(unused_unsafe)]
+unsafe fn mul(x: __m128i, y: __m128i) -> __m128i {
+    // SAFETY: pclmulqdq is enabled for this function and sse2 is baseline
+    unsafe {
+        let poly = _mm_set_epi64x(0xc200_0000_0000_0000_u64 as i64, 1);
+
+        let lo = _mm_clmulepi64_si128::<0x00>(x, y);
+        let hi = _mm_clmulepi64_si128::<0x11>(x, y);
+        let mid = _mm_xor_si128(
+            _mm_clmulepi64_si128::<0x10>(x, y),
+            _mm_clmulepi64_si128::<0x01>(x, y),
+        );
+        let lo = _mm_xor_si128(lo, _mm_bslli_si128::<8>(mid));
+        let hi = _mm_xor_si128(hi, _mm_bsrli_si128::<8>(mid));
+
+        let t = _mm_clmulepi64_si128::<0x10>(lo, poly);
+        let lo = _mm_xor_si128(t, _mm_shuffle_epi32::<0x4e>(lo));
+        let t = _mm_clmulepi64_si128::<0x10>(lo, poly);
+        let lo = _mm_xor_si128(t, _mm_shuffle_epi32::<0x4e>(lo));
+
+        _mm_xor_si128(hi, lo)
+    }
+}
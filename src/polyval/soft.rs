@@ -0,0 +1,72 @@
+// Constant-time carry-less multiplication with integer multipliers: the
+// operands are split into four interleaved bit classes so that carries land
+// in bits that get masked away. This is the BearSSL ghash_ctmul64 approach.
+
+use super::BLOCK_LEN;
+
+pub(crate) fn update_blocks(h: u128, mut acc: u128, blocks: &[[u8; BLOCK_LEN]]) -> u128 {
+    for block in blocks {
+        acc = mul(acc ^ u128::from_le_bytes(*block), h);
+    }
+
+    acc
+}
+
+// low 64 bits of the carry-less product of x and y
+#[inline(always)]
+fn bmul64(x: u64, y: u64) -> u64 {
+    const M0: u64 = 0x1111_1111_1111_1111;
+    const M1: u64 = M0 << 1;
+    const M2: u64 = M0 << 2;
+    const M3: u64 = M0 << 3;
+
+    let (x0, x1, x2, x3) = (x & M0, x & M1, x & M2, x & M3);
+    let (y0, y1, y2, y3) = (y & M0, y & M1, y & M2, y & M3);
+
+    let m = u64::wrapping_mul;
+
+    let z0 = m(x0, y0) ^ m(x1, y3) ^ m(x2, y2) ^ m(x3, y1);
+    let z1 = m(x0, y1) ^ m(x1, y0) ^ m(x2, y3) ^ m(x3, y2);
+    let z2 = m(x0, y2) ^ m(x1, y1) ^ m(x2, y0) ^ m(x3, y3);
+    let z3 = m(x0, y3) ^ m(x1, y2) ^ m(x2, y1) ^ m(x3, y0);
+
+    (z0 & M0) | (z1 & M1) | (z2 & M2) | (z3 & M3)
+}
+
+// x * y * x^-128, one Karatsuba step over 64-bit halves, the high halves of
+// the partial products come from the bit-reversed operands
+fn mul(x: u128, y: u128) -> u128 {
+    let (x0, x1) = (x as u64, (x >> 64) as u64);
+    let (y0, y1) = (y as u64, (y >> 64) as u64);
+    let (x2, y2) = (x0 ^ x1, y0 ^ y1);
+
+    let (x0r, x1r, x2r) = (x0.reverse_bits(), x1.reverse_bits(), x2.reverse_bits());
+    let (y0r, y1r, y2r) = (y0.reverse_bits(), y1.reverse_bits(), y2.reverse_bits());
+
+    let z0 = bmul64(x0, y0);
+    let z1 = bmul64(x1, y1);
+    let mut z2 = bmul64(x2, y2);
+    let mut z0h = bmul64(x0r, y0r);
+    let mut z1h = bmul64(x1r, y1r);
+    let mut z2h = bmul64(x2r, y2r);
+
+    z2 ^= z0 ^ z1;
+    z2h ^= z0h ^ z1h;
+    z0h = z0h.reverse_bits() >> 1;
+    z1h = z1h.reverse_bits() >> 1;
+    z2h = z2h.reverse_bits() >> 1;
+
+    // the 256-bit product v3:v2:v1:v0
+    let v0 = z0;
+    let mut v1 = z0h ^ z2;
+    let mut v2 = z1 ^ z2h;
+    let mut v3 = z1h;
+
+    // Montgomery reduction of the low 128 bits
+    v2 ^= v0 ^ (v0 >> 1) ^ (v0 >> 2) ^ (v0 >> 7);
+    v1 ^= (v0 << 63) ^ (v0 << 62) ^ (v0 << 57);
+    v3 ^= v1 ^ (v1 >> 1) ^ (v1 >> 2) ^ (v1 >> 7);
+    v2 ^= (v1 << 63) ^ (v1 << 62) ^ (v1 << 57);
+
+    u128::from(v2) | (u128::from(v3) << 64)
+}
@@ -0,0 +1,296 @@
+use std::fmt;
+
+#[cfg(feature = "rand")]
+use rand_core::CryptoRng;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+pub use crate::curve25519::Scalar;
+
+use crate::curve25519::field::{D, SQRT_M1};
+use crate::curve25519::{EdwardsPoint, FieldElement};
+use crate::sha2::Sha512;
+
+pub const ENCODED_LEN: usize = 32;
+pub const UNIFORM_BYTES_LEN: usize = 64;
+
+// sqrt(a * d - 1)
+const SQRT_AD_MINUS_ONE: FieldElement = FieldElement([
+    0x7f6a0497b2e1b,
+    0x1836f0a97afd2,
+    0x7d747f6be7638,
+    0x456079e7e6498,
+    0x376931bf2b834,
+]);
+
+// 1 / sqrt(a - d)
+const INVSQRT_A_MINUS_D: FieldElement = FieldElement([
+    0xfdaa805d40ea,
+    0x2eb482e57d339,
+    0x7610274bc58,
+    0x6510b613dc8ff,
+    0x786c8905cfaff,
+]);
+
+// 1 - d^2
+const ONE_MINUS_D_SQ: FieldElement = FieldElement([
+    0x409c1945fc176,
+    0x719abc6a1fc4f,
+    0x1c37f90b20684,
+    0x6bccca55eedf,
+    0x29072a8b2b3e,
+]);
+
+// (d - 1)^2
+const D_MINUS_ONE_SQ: FieldElement = FieldElement([
+    0x55aaa44ed4d20,
+    0x59603c3332635,
+    0x26d3baf4a7928,
+    0x120a66e6997a9,
+    0x5968b37af66c2,
+]);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    InvalidEncoding,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidEncoding => f.write_str("invalid ristretto255 encoding"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct CompressedRistretto([u8; ENCODED_LEN]);
+
+// An element of the ristretto255 group, represented by any edwards25519 point
+// of its coset. Equality is defined on the group, not on the representative.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize))]
+pub struct RistrettoPoint(EdwardsPoint);
+
+impl CompressedRistretto {
+    // RFC 9496 section 4.3.1
+    pub fn decompress(&self) -> Result<RistrettoPoint, Error> {
+        let s = FieldElement::from_bytes(&self.0);
+
+        // rejects non-canonical field elements, including a set top bit,
+        // and negative s
+        if s.to_bytes() != self.0 || s.is_negative() {
+            return Err(Error::InvalidEncoding);
+        }
+
+        let ss = s.square();
+        let u1 = FieldElement::ONE.sub(&ss);
+        let u2 = FieldElement::ONE.add(&ss);
+        let u2_sqr = u2.square();
+
+        // v = -(d * u1^2) - u2^2
+        let v = D.mul(&u1.square()).neg().sub(&u2_sqr);
+
+        let (was_square, invsqrt) = FieldElement::sqrt_ratio_i(&FieldElement::ONE, &v.mul(&u2_sqr));
+
+        let den_x = invsqrt.mul(&u2);
+        let den_y = invsqrt.mul(&den_x).mul(&v);
+
+        let mut x = s.add(&s).mul(&den_x);
+        let x_is_negative = x.is_negative();
+        x.conditional_negate(x_is_negative);
+
+        let y = u1.mul(&den_y);
+        let t = x.mul(&y);
+
+        if !was_square | t.is_negative() | y.is_zero() {
+            return Err(Error::InvalidEncoding);
+        }
+
+        Ok(RistrettoPoint(EdwardsPoint {
+            x,
+            y,
+            z: FieldElement::ONE,
+            t,
+        }))
+    }
+}
+
+impl TryFrom<&CompressedRistretto> for RistrettoPoint {
+    type Error = Error;
+
+    fn try_from(value: &CompressedRistretto) -> Result<Self, Self::Error> {
+        value.decompress()
+    }
+}
+
+impl RistrettoPoint {
+    pub const IDENTITY: Self = Self(EdwardsPoint::IDENTITY);
+
+    // the edwards25519 base point is also the ristretto255 generator
+    pub const fn basepoint() -> Self {
+        Self(EdwardsPoint::basepoint())
+    }
+
+    // RFC 9496 section 4.3.2
+    pub fn compress(&self) -> CompressedRistretto {
+        let EdwardsPoint { x, y, z, t } = self.0;
+
+        let u1 = z.add(&y).mul(&z.sub(&y));
+        let u2 = x.mul(&y);
+
+        let (_, invsqrt) = FieldElement::sqrt_ratio_i(&FieldElement::ONE, &u1.mul(&u2.square()));
+
+        let den1 = invsqrt.mul(&u1);
+        let den2 = invsqrt.mul(&u2);
+        let z_inv = den1.mul(&den2).mul(&t);
+
+        let ix = x.mul(&SQRT_M1);
+        let iy = y.mul(&SQRT_M1);
+        let enchanted_denominator = den1.mul(&INVSQRT_A_MINUS_D);
+
+        let rotate = t.mul(&z_inv).is_negative();
+
+        let mut x = x;
+        let mut y = y;
+        let mut den_inv = den2;
+        x.conditional_assign(&iy, rotate);
+        y.conditional_assign(&ix, rotate);
+        den_inv.conditional_assign(&enchanted_denominator, rotate);
+
+        y.conditional_negate(x.mul(&z_inv).is_negative());
+
+        let mut s = den_inv.mul(&z.sub(&y));
+        let s_is_negative = s.is_negative();
+        s.conditional_negate(s_is_negative);
+
+        CompressedRistretto(s.to_bytes())
+    }
+
+    // RFC 9496 section 4.3.4, the input is reduced as two field elements
+    // with the top bits ignored
+    pub fn from_uniform_bytes(bytes: &[u8; UNIFORM_BYTES_LEN]) -> Self {
+        let (lo, hi) = bytes.split_at(32);
+
+        let p1 = elligator(&FieldElement::from_bytes(lo.try_into().unwrap()));
+        let p2 = elligator(&FieldElement::from_bytes(hi.try_into().unwrap()));
+
+        Self(p1.add(&p2))
+    }
+
+    // hashes arbitrary input to the group with SHA-512, see RFC 9496
+    // appendix B for the requirements on the hash
+    pub fn hash_from_bytes(input: &[u8]) -> Self {
+        Self::from_uniform_bytes(&Sha512::digest(input))
+    }
+
+    #[cfg(feature = "rand")]
+    pub fn random_from_rng<R: CryptoRng + ?Sized>(rng: &mut R) -> Self {
+        let mut bytes = [0; UNIFORM_BYTES_LEN];
+        rng.fill_bytes(&mut bytes);
+
+        Self::from_uniform_bytes(&bytes)
+    }
+
+    pub fn add(&self, rhs: &Self) -> Self {
+        Self(self.0.add(&rhs.0))
+    }
+
+    pub fn sub(&self, rhs: &Self) -> Self {
+        Self(self.0.sub(&rhs.0))
+    }
+
+    pub fn neg(&self) -> Self {
+        Self(self.0.neg())
+    }
+
+    pub fn double(&self) -> Self {
+        Self(self.0.double())
+    }
+
+    pub fn mul(&self, scalar: &Scalar) -> Self {
+        Self(self.0.mul(scalar))
+    }
+
+    pub fn mul_base(scalar: &Scalar) -> Self {
+        Self(EdwardsPoint::mul_base(scalar))
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.ct_eq(&Self::IDENTITY)
+    }
+
+    // RFC 9496 section 4.3.3, points of the same coset compare equal
+    pub fn ct_eq(&self, rhs: &Self) -> bool {
+        let (a, b) = (&self.0, &rhs.0);
+
+        let x1y2 = a.x.mul(&b.y);
+        let y1x2 = a.y.mul(&b.x);
+        let y1y2 = a.y.mul(&b.y);
+        let x1x2 = a.x.mul(&b.x);
+
+        x1y2.ct_eq(&y1x2) | y1y2.ct_eq(&x1x2)
+    }
+}
+
+impl Default for RistrettoPoint {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl PartialEq for RistrettoPoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other)
+    }
+}
+
+impl Eq for RistrettoPoint {}
+
+impl From<&RistrettoPoint> for CompressedRistretto {
+    fn from(value: &RistrettoPoint) -> Self {
+        value.compress()
+    }
+}
+
+// the MAP function of RFC 9496 section 4.3.4, a ristretto flavoured
+// elligator 2
+fn elligator(t: &FieldElement) -> EdwardsPoint {
+    let one = FieldElement::ONE;
+
+    let r = SQRT_M1.mul(&t.square());
+    let u = r.add(&one).mul(&ONE_MINUS_D_SQ);
+    let v = one.neg().sub(&r.mul(&D)).mul(&r.add(&D));
+
+    let (was_square, mut s) = FieldElement::sqrt_ratio_i(&u, &v);
+
+    let mut s_prime = s.mul(t);
+    let s_prime_is_negative = s_prime.is_negative();
+    s_prime.conditional_negate(!s_prime_is_negative);
+
+    s.conditional_assign(&s_prime, !was_square);
+
+    let mut c = one.neg();
+    c.conditional_assign(&r, !was_square);
+
+    let n = c.mul(&r.sub(&one)).mul(&D_MINUS_ONE_SQ).sub(&v);
+
+    let ss = s.square();
+    let w0 = s.add(&s).mul(&v);
+    let w1 = n.mul(&SQRT_AD_MINUS_ONE);
+    let w2 = one.sub(&ss);
+    let w3 = one.add(&ss);
+
+    EdwardsPoint {
+        x: w0.mul(&w3),
+        y: w2.mul(&w1),
+        z: w1.mul(&w3),
+        t: w0.mul(&w2),
+    }
+}
+
+bytes_wrapper_impl!(CompressedRistretto, ENCODED_LEN, public);
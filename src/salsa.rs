@@ -0,0 +1,53 @@
+pub const STATE_LEN_WORDS: usize = 16;
+
+#[inline(always)]
+fn quarter_round(state: &mut [u32; STATE_LEN_WORDS], a: usize, b: usize, c: usize, d: usize) {
+    // b ^= (a + d) <<< 7
+    state[b] ^= state[a].wrapping_add(state[d]).rotate_left(7);
+
+    // c ^= (b + a) <<< 9
+    state[c] ^= state[b].wrapping_add(state[a]).rotate_left(9);
+
+    // d ^= (c + b) <<< 13
+    state[d] ^= state[c].wrapping_add(state[b]).rotate_left(13);
+
+    // a ^= (d + c) <<< 18
+    state[a] ^= state[d].wrapping_add(state[c]).rotate_left(18);
+}
+
+#[inline(always)]
+fn column_round(state: &mut [u32; STATE_LEN_WORDS]) {
+    quarter_round(state, 0, 4, 8, 12);
+    quarter_round(state, 5, 9, 13, 1);
+    quarter_round(state, 10, 14, 2, 6);
+    quarter_round(state, 15, 3, 7, 11);
+}
+
+#[inline(always)]
+fn row_round(state: &mut [u32; STATE_LEN_WORDS]) {
+    quarter_round(state, 0, 1, 2, 3);
+    quarter_round(state, 5, 6, 7, 4);
+    quarter_round(state, 10, 11, 8, 9);
+    quarter_round(state, 15, 12, 13, 14);
+}
+
+#[inline(always)]
+pub fn salsa_core<const ROUNDS: usize>(state: &mut [u32; STATE_LEN_WORDS]) {
+    let mut working = *state;
+
+    for i in 0..ROUNDS {
+        match i % 2 == 0 {
+            true => column_round(&mut working),
+            false => row_round(&mut working),
+        }
+    }
+
+    (0..STATE_LEN_WORDS).for_each(|i| {
+        state[i] = state[i].wrapping_add(working[i]);
+    });
+}
+
+#[inline(always)]
+pub fn salsa20_8(state: &mut [u32; STATE_LEN_WORDS]) {
+    salsa_core::<8>(state);
+}
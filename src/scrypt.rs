@@ -0,0 +1,192 @@
+use std::fmt;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use crate::pbkdf2::pbkdf2_hmac_sha256;
+use crate::salsa::{STATE_LEN_WORDS, salsa20_8};
+use crate::sha2::sha256::OUTPUT_LEN;
+use crate::utils::{bytes_to_words, words_to_bytes};
+
+// recommended interactive-login parameters from the scrypt paper
+pub const RECOMMENDED_LOG_N: u8 = 15;
+pub const RECOMMENDED_R: u32 = 8;
+pub const RECOMMENDED_P: u32 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    InvalidLogN,
+    InvalidR,
+    InvalidP,
+    InvalidOutputLen,
+    MemoryLimit,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::InvalidLogN => {
+                "scrypt cost parameter N must be a power of two with 0 < log2(N) < 16 * r"
+            }
+            Self::InvalidR => "scrypt block size parameter r must be positive",
+            Self::InvalidP => "scrypt parallelization parameter p must satisfy 0 < p * r < 2^30",
+            Self::InvalidOutputLen => "scrypt output length must be in 1..=(2^32 - 1) * 32",
+            Self::MemoryLimit => "scrypt parameters require more memory than is addressable",
+        };
+
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    log_n: u8,
+    r: u32,
+    p: u32,
+}
+
+impl Params {
+    pub fn new(log_n: u8, r: u32, p: u32) -> Result<Self, Error> {
+        if r == 0 {
+            return Err(Error::InvalidR);
+        }
+
+        // N = 2^log_n must be > 1 and < 2^(128 * r / 8)
+        if log_n == 0 || log_n >= 64 || u64::from(log_n) >= 16 * u64::from(r) {
+            return Err(Error::InvalidLogN);
+        }
+
+        // p * r must stay below 2^30
+        if p == 0 || u64::from(p) * u64::from(r) >= 1 << 30 {
+            return Err(Error::InvalidP);
+        }
+
+        // V holds N blocks of 128 * r bytes and must be addressable
+        let block_len = 128_usize
+            .checked_mul(r as usize)
+            .ok_or(Error::MemoryLimit)?;
+        1_usize
+            .checked_shl(u32::from(log_n))
+            .and_then(|n| n.checked_mul(block_len))
+            .ok_or(Error::MemoryLimit)?;
+
+        Ok(Self { log_n, r, p })
+    }
+
+    pub const fn log_n(&self) -> u8 {
+        self.log_n
+    }
+
+    pub const fn n(&self) -> u64 {
+        1 << self.log_n
+    }
+
+    pub const fn r(&self) -> u32 {
+        self.r
+    }
+
+    pub const fn p(&self) -> u32 {
+        self.p
+    }
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            log_n: RECOMMENDED_LOG_N,
+            r: RECOMMENDED_R,
+            p: RECOMMENDED_P,
+        }
+    }
+}
+
+pub fn scrypt(password: &[u8], salt: &[u8], params: &Params, dst: &mut [u8]) -> Result<(), Error> {
+    if dst.is_empty() || (dst.len() as u64) > u64::from(u32::MAX) * OUTPUT_LEN as u64 {
+        return Err(Error::InvalidOutputLen);
+    }
+
+    let r = params.r as usize;
+    let p = params.p as usize;
+    let n = 1_usize << params.log_n;
+
+    let block_len = 128 * r;
+    let block_len_words = block_len / 4;
+
+    // p * 128 * r passes `Params::new` but can still overflow a 32-bit usize
+    let b_len = p.checked_mul(block_len).ok_or(Error::MemoryLimit)?;
+    let v_len = n.checked_mul(block_len_words).ok_or(Error::MemoryLimit)?;
+
+    // B = PBKDF2-HMAC-SHA256(P, S, 1, p * 128 * r)
+    let mut b = vec![0_u8; b_len];
+    pbkdf2_hmac_sha256(password, salt, 1, &mut b);
+
+    let mut x = vec![0_u32; block_len_words];
+    let mut y = vec![0_u32; block_len_words];
+    let mut v = vec![0_u32; v_len];
+
+    for chunk in b.chunks_exact_mut(block_len) {
+        bytes_to_words(chunk, &mut x);
+        ro_mix(&mut x, &mut y, &mut v, n);
+        words_to_bytes(&x, chunk);
+    }
+
+    // DK = PBKDF2-HMAC-SHA256(P, B, 1, dkLen)
+    pbkdf2_hmac_sha256(password, &b, 1, dst);
+
+    #[cfg(feature = "zeroize")]
+    {
+        b.zeroize();
+        x.zeroize();
+        y.zeroize();
+        v.zeroize();
+    }
+
+    Ok(())
+}
+
+fn ro_mix(x: &mut [u32], y: &mut [u32], v: &mut [u32], n: usize) {
+    let block_len_words = x.len();
+
+    for chunk in v.chunks_exact_mut(block_len_words) {
+        chunk.copy_from_slice(x);
+        block_mix(x, y);
+    }
+
+    for _ in 0..n {
+        let j = integerify(x) & (n - 1);
+        let v_j = &v[j * block_len_words..(j + 1) * block_len_words];
+        x.iter_mut().zip(v_j).for_each(|(x, v)| *x ^= *v);
+        block_mix(x, y);
+    }
+}
+
+// scryptBlockMix: the even Salsa20/8 outputs go to the first half of the
+// block and the odd ones to the second half; `y` is scratch space
+fn block_mix(b: &mut [u32], y: &mut [u32]) {
+    let half = b.len() / 2;
+
+    let mut x = [0_u32; STATE_LEN_WORDS];
+    x.copy_from_slice(&b[b.len() - STATE_LEN_WORDS..]);
+
+    for (i, chunk) in b.chunks_exact(STATE_LEN_WORDS).enumerate() {
+        x.iter_mut().zip(chunk).for_each(|(x, b)| *x ^= *b);
+        salsa20_8(&mut x);
+
+        let offset = (i / 2) * STATE_LEN_WORDS + (i % 2) * half;
+        y[offset..offset + STATE_LEN_WORDS].copy_from_slice(&x);
+    }
+
+    b.copy_from_slice(y);
+
+    #[cfg(feature = "zeroize")]
+    x.zeroize();
+}
+
+#[inline(always)]
+fn integerify(b: &[u32]) -> usize {
+    let last = &b[b.len() - STATE_LEN_WORDS..];
+
+    (u64::from(last[0]) | (u64::from(last[1]) << 32)) as usize
+}
@@ -0,0 +1,5 @@
+pub use self::sha256::Sha256;
+pub use self::sha512::Sha512;
+
+pub mod sha256;
+pub mod sha512;
@@ -1,3 +1,10 @@
+pub use self::codec::{
+    decode_base64, decode_base64_into, decode_base64_url, decode_base64_url_into, decode_hex,
+    decode_hex_into, encode_base64, encode_base64_url, encode_hex,
+};
+
+mod codec;
+
 #[inline(always)]
 pub(crate) fn bytes_to_words(src: &[u8], dst: &mut [u32]) {
     for (i, chunk) in src.chunks_exact(4).enumerate() {
@@ -60,7 +67,68 @@ generate_bytes_to_words!(bytes12_to_words3, 3);
 generate_bytes_to_words!(bytes16_to_words4, 4);
 generate_bytes_to_words!(bytes32_to_words8, 8);
 
+// `secret` wrappers compare in constant time and never print their bytes,
+// `public` ones print as hex; `random` adds generation for keys and nonces
 macro_rules! bytes_wrapper_impl {
+    ($struct_name:ident, $len:ident, $kind:ident, random) => {
+        bytes_wrapper_impl!($struct_name, $len, $kind);
+
+        impl $struct_name {
+            #[cfg(feature = "rand")]
+            pub fn random_from_rng<R: rand_core::CryptoRng + ?Sized>(rng: &mut R) -> Self {
+                let mut value = Self([0; $len]);
+                rng.fill_bytes(&mut value.0);
+
+                value
+            }
+
+            pub fn try_random() -> Result<Self, $crate::os::Error> {
+                let mut value = Self([0; $len]);
+                $crate::os::try_fill_random(&mut value.0)?;
+
+                Ok(value)
+            }
+
+            // panics when the OS has no entropy to offer
+            pub fn random() -> Self {
+                let mut value = Self([0; $len]);
+                $crate::os::fill_random(&mut value.0);
+
+                value
+            }
+        }
+    };
+
+    ($struct_name:ident, $len:ident, secret) => {
+        bytes_wrapper_impl!($struct_name, $len);
+
+        impl PartialEq for $struct_name {
+            fn eq(&self, other: &Self) -> bool {
+                $crate::ct::ConstantTimeEq::ct_eq(self, other)
+            }
+        }
+
+        impl Eq for $struct_name {}
+
+        impl std::fmt::Debug for $struct_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(concat!(stringify!($struct_name), "(<redacted>)"))
+            }
+        }
+    };
+
+    ($struct_name:ident, $len:ident, public) => {
+        bytes_wrapper_impl!($struct_name, $len);
+
+        impl std::fmt::Debug for $struct_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, concat!(stringify!($struct_name), "("))?;
+                self.0.iter().try_for_each(|b| write!(f, "{b:02x}"))?;
+                f.write_str(")")
+            }
+        }
+    };
+
     ($struct_name:ident, $len:ident) => {
         impl $struct_name {
             pub const fn new(bytes: [u8; $len]) -> Self {
@@ -86,6 +154,80 @@ macro_rules! bytes_wrapper_impl {
             pub const fn bytes_mut(&mut self) -> &mut [u8; $len] {
                 &mut self.0
             }
+
+            // the text forms decode straight into `Self`, with the `zeroize`
+            // feature a rejected, partly decoded secret is wiped
+            pub fn from_hex(src: &str) -> Result<Self, $crate::Error> {
+                let mut value = Self([0; $len]);
+                let result = $crate::utils::decode_hex_into(src, &mut value.0);
+
+                value.decoded(result)
+            }
+
+            pub fn from_base64(src: &str) -> Result<Self, $crate::Error> {
+                let mut value = Self([0; $len]);
+                let result = $crate::utils::decode_base64_into(src, &mut value.0);
+
+                value.decoded(result)
+            }
+
+            pub fn from_base64_url(src: &str) -> Result<Self, $crate::Error> {
+                let mut value = Self([0; $len]);
+                let result = $crate::utils::decode_base64_url_into(src, &mut value.0);
+
+                value.decoded(result)
+            }
+
+            #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+            fn decoded(mut self, result: Result<(), $crate::Error>) -> Result<Self, $crate::Error> {
+                match result {
+                    Ok(()) => Ok(self),
+                    Err(err) => {
+                        #[cfg(feature = "zeroize")]
+                        zeroize::Zeroize::zeroize(&mut self.0);
+
+                        Err(err)
+                    }
+                }
+            }
+
+            pub fn to_hex(&self) -> String {
+                $crate::utils::encode_hex(&self.0)
+            }
+
+            pub fn to_base64(&self) -> String {
+                $crate::utils::encode_base64(&self.0)
+            }
+
+            pub fn to_base64_url(&self) -> String {
+                $crate::utils::encode_base64_url(&self.0)
+            }
+        }
+
+        // parses hex
+        impl std::str::FromStr for $struct_name {
+            type Err = $crate::Error;
+
+            fn from_str(src: &str) -> Result<Self, Self::Err> {
+                Self::from_hex(src)
+            }
+        }
+
+        impl $crate::ct::ConstantTimeEq for $struct_name {
+            #[inline]
+            fn ct_eq(&self, other: &Self) -> bool {
+                $crate::ct::ConstantTimeEq::ct_eq(&self.0, &other.0)
+            }
+        }
+
+        impl $crate::ct::ConditionallySelectable for $struct_name {
+            #[inline]
+            fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+                let select =
+                    <[u8; $len] as $crate::ct::ConditionallySelectable>::conditional_select;
+
+                Self(select(&a.0, &b.0, choice))
+            }
         }
 
         impl From<[u8; $len]> for $struct_name {
@@ -94,6 +236,20 @@ macro_rules! bytes_wrapper_impl {
             }
         }
 
+        impl TryFrom<&[u8]> for $struct_name {
+            type Error = $crate::Error;
+
+            fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+                match <[u8; $len]>::try_from(value) {
+                    Ok(bytes) => Ok(Self(bytes)),
+                    Err(_) => Err($crate::Error::InvalidLength {
+                        expected: $len,
+                        actual: value.len(),
+                    }),
+                }
+            }
+        }
+
         impl AsRef<[u8]> for $struct_name {
             fn as_ref(&self) -> &[u8] {
                 self.bytes()
@@ -0,0 +1,253 @@
+// Hex and base64 codecs that run in time independent of the data, so keys
+// can be parsed from configuration without leaking through cache or branch
+// timing. Only the lengths of the inputs are public.
+
+use crate::Error;
+
+const BASE64_PAD: u8 = b'=';
+
+// all ones when lo <= c <= hi, zero otherwise
+#[inline(always)]
+fn ct_in_range(c: u8, lo: u8, hi: u8) -> i16 {
+    let c = i16::from(c);
+
+    ((i16::from(lo) - 1 - c) & (c - i16::from(hi) - 1)) >> 8
+}
+
+// all ones when a > b, zero otherwise, for a and b below 256
+#[inline(always)]
+fn ct_gt(a: u8, b: u8) -> i16 {
+    (i16::from(b) - i16::from(a)) >> 8
+}
+
+#[inline(always)]
+fn encode_nibble(n: u8) -> u8 {
+    // '0' + n, shifted by 'a' - '9' - 1 for n > 9
+    (i16::from(n) + 48 + (ct_gt(n, 9) & 39)) as u8
+}
+
+// the nibble, or -1 for a character outside [0-9a-fA-F]
+#[inline(always)]
+fn decode_nibble(c: u8) -> i16 {
+    let c16 = i16::from(c);
+
+    -1 + (ct_in_range(c, b'0', b'9') & (c16 - 47))
+        + (ct_in_range(c, b'a', b'f') & (c16 - 86))
+        + (ct_in_range(c, b'A', b'F') & (c16 - 54))
+}
+
+#[inline(always)]
+fn encode_sextet(v: u8, url: bool) -> u8 {
+    let (sixty_two, sixty_three) = match url {
+        true => (b'-', b'_'),
+        false => (b'+', b'/'),
+    };
+
+    let mut diff = i16::from(b'A');
+    diff += ct_gt(v, 25) & 6;
+    diff -= ct_gt(v, 51) & 75;
+    diff += ct_gt(v, 61) & (i16::from(sixty_two) - 62 + 4);
+    diff += ct_gt(v, 62) & (i16::from(sixty_three) - i16::from(sixty_two) - 1);
+
+    (i16::from(v) + diff) as u8
+}
+
+// the sextet, or -1 for a character outside the alphabet
+#[inline(always)]
+fn decode_sextet(c: u8, url: bool) -> i16 {
+    let (sixty_two, sixty_three) = match url {
+        true => (b'-', b'_'),
+        false => (b'+', b'/'),
+    };
+    let c16 = i16::from(c);
+
+    -1 + (ct_in_range(c, b'A', b'Z') & (c16 - 64))
+        + (ct_in_range(c, b'a', b'z') & (c16 - 70))
+        + (ct_in_range(c, b'0', b'9') & (c16 + 5))
+        + (ct_in_range(c, sixty_two, sixty_two) & 63)
+        + (ct_in_range(c, sixty_three, sixty_three) & 64)
+}
+
+// lowercase hex
+pub fn encode_hex(src: &[u8]) -> String {
+    let mut out = Vec::with_capacity(src.len() * 2);
+
+    for &b in src {
+        out.push(encode_nibble(b >> 4));
+        out.push(encode_nibble(b & 0x0f));
+    }
+
+    // the alphabet is ascii
+    String::from_utf8(out).unwrap()
+}
+
+// accepts both cases, `dst` must be exactly half the length of `src`
+pub fn decode_hex_into(src: &str, dst: &mut [u8]) -> Result<(), Error> {
+    let src = src.as_bytes();
+
+    if src.len() % 2 != 0 {
+        return Err(Error::InvalidEncoding);
+    }
+
+    if src.len() / 2 != dst.len() {
+        return Err(Error::InvalidLength {
+            expected: dst.len(),
+            actual: src.len() / 2,
+        });
+    }
+
+    let mut invalid = 0;
+
+    for (byte, pair) in dst.iter_mut().zip(src.chunks_exact(2)) {
+        let hi = decode_nibble(pair[0]);
+        let lo = decode_nibble(pair[1]);
+
+        invalid |= hi | lo;
+        *byte = ((hi << 4) | lo) as u8;
+    }
+
+    match invalid < 0 {
+        true => Err(Error::InvalidEncoding),
+        false => Ok(()),
+    }
+}
+
+pub fn decode_hex(src: &str) -> Result<Vec<u8>, Error> {
+    let mut out = vec![0; src.len() / 2];
+    decode_hex_into(src, &mut out)?;
+
+    Ok(out)
+}
+
+// RFC 4648 section 4, padded
+pub fn encode_base64(src: &[u8]) -> String {
+    encode_base64_impl(src, false)
+}
+
+// RFC 4648 section 5, unpadded as is usual for URLs
+pub fn encode_base64_url(src: &[u8]) -> String {
+    encode_base64_impl(src, true)
+}
+
+pub fn decode_base64_into(src: &str, dst: &mut [u8]) -> Result<(), Error> {
+    decode_base64_impl(src.as_bytes(), dst, false)
+}
+
+pub fn decode_base64_url_into(src: &str, dst: &mut [u8]) -> Result<(), Error> {
+    decode_base64_impl(src.as_bytes(), dst, true)
+}
+
+pub fn decode_base64(src: &str) -> Result<Vec<u8>, Error> {
+    let mut out = vec![0; base64_decoded_len(src.as_bytes(), false)?];
+    decode_base64_into(src, &mut out)?;
+
+    Ok(out)
+}
+
+pub fn decode_base64_url(src: &str) -> Result<Vec<u8>, Error> {
+    let mut out = vec![0; base64_decoded_len(src.as_bytes(), true)?];
+    decode_base64_url_into(src, &mut out)?;
+
+    Ok(out)
+}
+
+fn encode_base64_impl(src: &[u8], url: bool) -> String {
+    let mut out = Vec::with_capacity(src.len().div_ceil(3) * 4);
+
+    for chunk in src.chunks(3) {
+        let mut block = [0; 3];
+        block[..chunk.len()].copy_from_slice(chunk);
+
+        let sextets = [
+            block[0] >> 2,
+            ((block[0] & 0x03) << 4) | (block[1] >> 4),
+            ((block[1] & 0x0f) << 2) | (block[2] >> 6),
+            block[2] & 0x3f,
+        ];
+
+        let used = chunk.len() + 1;
+        out.extend(sextets[..used].iter().map(|&v| encode_sextet(v, url)));
+
+        if !url {
+            out.resize(out.len() + 4 - used, BASE64_PAD);
+        }
+    }
+
+    // the alphabet is ascii
+    String::from_utf8(out).unwrap()
+}
+
+// the padding is public, it follows from the length of the data
+fn base64_decoded_len(src: &[u8], url: bool) -> Result<usize, Error> {
+    let unpadded = match url {
+        true => src.len(),
+        false => {
+            if src.len() % 4 != 0 {
+                return Err(Error::InvalidEncoding);
+            }
+
+            let padding = src
+                .iter()
+                .rev()
+                .take(2)
+                .take_while(|&&c| c == BASE64_PAD)
+                .count();
+            src.len() - padding
+        }
+    };
+
+    match unpadded % 4 {
+        1 => Err(Error::InvalidEncoding),
+        rest => Ok(unpadded / 4 * 3 + rest.saturating_sub(1)),
+    }
+}
+
+fn decode_base64_impl(src: &[u8], dst: &mut [u8], url: bool) -> Result<(), Error> {
+    let len = base64_decoded_len(src, url)?;
+
+    if len != dst.len() {
+        return Err(Error::InvalidLength {
+            expected: dst.len(),
+            actual: len,
+        });
+    }
+
+    let chars = match len % 3 {
+        0 => len / 3 * 4,
+        rest => len / 3 * 4 + rest + 1,
+    };
+
+    let mut invalid = 0;
+    let mut out = dst.iter_mut();
+
+    for chunk in src[..chars].chunks(4) {
+        let mut sextets = [0_i16; 4];
+        for (sextet, &c) in sextets.iter_mut().zip(chunk) {
+            *sextet = decode_sextet(c, url);
+            invalid |= *sextet;
+        }
+
+        let bytes = [
+            (sextets[0] << 2) | (sextets[1] >> 4),
+            (sextets[1] << 4) | (sextets[2] >> 2),
+            (sextets[2] << 6) | sextets[3],
+        ];
+
+        // bits past the last byte must be zero for a canonical encoding
+        invalid |= match chunk.len() {
+            2 => -i16::from(sextets[1] & 0x0f != 0),
+            3 => -i16::from(sextets[2] & 0x03 != 0),
+            _ => 0,
+        };
+
+        // the chunk is zipped first so no extra output byte is consumed
+        for (value, byte) in bytes[..chunk.len() - 1].iter().zip(out.by_ref()) {
+            *byte = *value as u8;
+        }
+    }
+
+    match invalid < 0 {
+        true => Err(Error::InvalidEncoding),
+        false => Ok(()),
+    }
+}
@@ -0,0 +1,135 @@
+use std::fmt;
+
+#[cfg(feature = "rand")]
+use rand_core::CryptoRng;
+
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::curve25519::montgomery;
+use crate::os;
+
+pub const KEY_LEN: usize = 32;
+
+pub const BASEPOINT: [u8; KEY_LEN] = {
+    let mut bytes = [0; KEY_LEN];
+    bytes[0] = 9;
+    bytes
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    LowOrderPoint,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LowOrderPoint => f.write_str("x25519 public key is a low-order point"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct PublicKey([u8; KEY_LEN]);
+
+#[derive(Clone)]
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+#[repr(transparent)]
+pub struct StaticSecret([u8; KEY_LEN]);
+
+// Single-use secret: it cannot be cloned, serialized or reused, and is
+// consumed by the key agreement.
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+pub struct EphemeralSecret([u8; KEY_LEN]);
+
+#[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
+#[repr(transparent)]
+pub struct SharedSecret([u8; KEY_LEN]);
+
+pub fn x25519(scalar: [u8; KEY_LEN], u: [u8; KEY_LEN]) -> [u8; KEY_LEN] {
+    montgomery::mul(&clamp(scalar), &u)
+}
+
+#[inline(always)]
+fn clamp(mut scalar: [u8; KEY_LEN]) -> [u8; KEY_LEN] {
+    scalar[0] &= 248;
+    scalar[31] &= 127;
+    scalar[31] |= 64;
+
+    scalar
+}
+
+fn diffie_hellman(secret: &[u8; KEY_LEN], public: &PublicKey) -> Result<SharedSecret, Error> {
+    let shared = SharedSecret(x25519(*secret, public.0));
+
+    // low-order inputs force an all-zero output, checked in constant time
+    let acc = shared.0.iter().fold(0_u8, |acc, b| acc | b);
+
+    match acc == 0 {
+        true => Err(Error::LowOrderPoint),
+        false => Ok(shared),
+    }
+}
+
+impl StaticSecret {
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey(x25519(self.0, BASEPOINT))
+    }
+
+    pub fn diffie_hellman(&self, public: &PublicKey) -> Result<SharedSecret, Error> {
+        diffie_hellman(&self.0, public)
+    }
+}
+
+impl EphemeralSecret {
+    #[cfg(feature = "rand")]
+    pub fn random_from_rng<R: CryptoRng + ?Sized>(rng: &mut R) -> Self {
+        let mut bytes = [0; KEY_LEN];
+        rng.fill_bytes(&mut bytes);
+
+        Self(bytes)
+    }
+
+    pub fn try_random() -> Result<Self, os::Error> {
+        let mut bytes = [0; KEY_LEN];
+        os::try_fill_random(&mut bytes)?;
+
+        Ok(Self(bytes))
+    }
+
+    // panics when the OS has no entropy to offer
+    pub fn random() -> Self {
+        let mut bytes = [0; KEY_LEN];
+        os::fill_random(&mut bytes);
+
+        Self(bytes)
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey(x25519(self.0, BASEPOINT))
+    }
+
+    pub fn diffie_hellman(self, public: &PublicKey) -> Result<SharedSecret, Error> {
+        diffie_hellman(&self.0, public)
+    }
+}
+
+impl From<&StaticSecret> for PublicKey {
+    fn from(secret: &StaticSecret) -> Self {
+        secret.public_key()
+    }
+}
+
+impl From<&EphemeralSecret> for PublicKey {
+    fn from(secret: &EphemeralSecret) -> Self {
+        secret.public_key()
+    }
+}
+
+bytes_wrapper_impl!(PublicKey, KEY_LEN, public);
+bytes_wrapper_impl!(StaticSecret, KEY_LEN, secret, random);
+bytes_wrapper_impl!(SharedSecret, KEY_LEN, secret);
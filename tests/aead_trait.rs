@@ -0,0 +1,78 @@
+use purecrypt::aead::{Aead, Error};
+use purecrypt::aes::{Aes128, Aes256, Key128, Key256};
+use purecrypt::aes_gcm::{AesGcm, Nonce};
+use purecrypt::aes_gcm_siv::AesGcmSiv;
+use purecrypt::chacha::{Key, XNonce};
+use purecrypt::chacha20_poly1305_siv::ChaCha20Poly1305Siv;
+
+// exercises an AEAD only through the trait
+fn roundtrip<A: Aead>(key: &A::Key, nonce: &A::Nonce) {
+    let cipher = A::new(key);
+    let message: Vec<u8> = (0..100).map(|i| i as u8).collect();
+
+    for len in [0, 1, 16, 17, 100] {
+        let plaintext = &message[..len];
+
+        let sealed = cipher.seal(nonce, b"aad", plaintext).unwrap();
+        assert_eq!(sealed.len(), len + A::TAG_LEN);
+        assert_eq!(cipher.open(nonce, b"aad", &sealed).unwrap(), plaintext);
+
+        let mut buffer = plaintext.to_vec();
+        let tag = cipher
+            .encrypt_in_place_detached(nonce, b"aad", &mut buffer)
+            .unwrap();
+        assert_eq!(buffer, &sealed[..len]);
+        assert_eq!(tag.as_ref(), &sealed[len..]);
+
+        cipher
+            .decrypt_in_place_detached(nonce, b"aad", &mut buffer, &tag)
+            .unwrap();
+        assert_eq!(buffer, plaintext);
+
+        let mut forged = sealed.clone();
+        forged[0] ^= 1;
+        assert_eq!(cipher.open(nonce, b"aad", &forged), Err(Error::InvalidTag));
+        assert_eq!(
+            cipher.open(nonce, b"other", &sealed),
+            Err(Error::InvalidTag)
+        );
+    }
+
+    assert_eq!(
+        cipher.open(nonce, b"aad", &vec![0; A::TAG_LEN - 1]),
+        Err(Error::InvalidTag)
+    );
+}
+
+#[test]
+fn aead_trait_roundtrips() {
+    let nonce = Nonce::new([3; 12]);
+
+    roundtrip::<AesGcm<Aes128>>(&Key128::new([1; 16]), &nonce);
+    roundtrip::<AesGcm<Aes256>>(&Key256::new([1; 32]), &nonce);
+    roundtrip::<AesGcmSiv<Aes128>>(&Key128::new([1; 16]), &nonce);
+    roundtrip::<AesGcmSiv<Aes256>>(&Key256::new([1; 32]), &nonce);
+    roundtrip::<ChaCha20Poly1305Siv>(&Key::new([1; 32]), &XNonce::new([3; 24]));
+}
+
+#[test]
+fn aead_trait_sizes() {
+    assert_eq!(
+        (
+            AesGcm::<Aes128>::KEY_LEN,
+            AesGcm::<Aes128>::NONCE_LEN,
+            AesGcm::<Aes128>::TAG_LEN
+        ),
+        (16, 12, 16)
+    );
+    assert_eq!(AesGcm::<Aes256>::KEY_LEN, 32);
+    assert_eq!(AesGcmSiv::<Aes256>::KEY_LEN, 32);
+    assert_eq!(
+        (
+            ChaCha20Poly1305Siv::KEY_LEN,
+            ChaCha20Poly1305Siv::NONCE_LEN,
+            ChaCha20Poly1305Siv::TAG_LEN
+        ),
+        (32, 24, 16)
+    );
+}
@@ -0,0 +1,293 @@
+use hex_literal::hex;
+use purecrypt::aes::{Aes128, Aes192, Aes256, BlockCipher, Key128, Key192, Key256};
+
+// runs every vector through the default (possibly AES-NI) backend and the
+// software backend
+fn check<C: BlockCipher>(ciphers: [C; 2], plaintext: [u8; 16], ciphertext: [u8; 16]) {
+    for cipher in ciphers {
+        let mut block = plaintext;
+        cipher.encrypt_block(&mut block);
+        assert_eq!(block, ciphertext);
+
+        cipher.decrypt_block(&mut block);
+        assert_eq!(block, plaintext);
+    }
+}
+
+fn aes128(key: [u8; 16]) -> [Aes128; 2] {
+    let key = Key128::new(key);
+    [Aes128::new(&key), Aes128::new_soft(&key)]
+}
+
+fn aes192(key: [u8; 24]) -> [Aes192; 2] {
+    let key = Key192::new(key);
+    [Aes192::new(&key), Aes192::new_soft(&key)]
+}
+
+fn aes256(key: [u8; 32]) -> [Aes256; 2] {
+    let key = Key256::new(key);
+    [Aes256::new(&key), Aes256::new_soft(&key)]
+}
+
+#[test]
+fn aes_fips197_appendix_c() {
+    // FIPS-197 appendix C example vectors:
+    // https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.197-upd1.pdf
+    let plaintext = hex!("00112233445566778899aabbccddeeff");
+
+    check(
+        aes128(hex!("000102030405060708090a0b0c0d0e0f")),
+        plaintext,
+        hex!("69c4e0d86a7b0430d8cdb78070b4c55a"),
+    );
+    check(
+        aes192(hex!("000102030405060708090a0b0c0d0e0f1011121314151617")),
+        plaintext,
+        hex!("dda97ca4864cdfe06eaf70a0ec0d7191"),
+    );
+    check(
+        aes256(hex!(
+            "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        )),
+        plaintext,
+        hex!("8ea2b7ca516745bfeafc49904b496089"),
+    );
+}
+
+#[test]
+fn aes_fips197_appendix_b() {
+    // FIPS-197 appendix B cipher example
+    check(
+        aes128(hex!("2b7e151628aed2a6abf7158809cf4f3c")),
+        hex!("3243f6a8885a308d313198a2e0370734"),
+        hex!("3925841d02dc09fbdc118597196a0b32"),
+    );
+}
+
+#[test]
+fn aes_cavp_gfsbox() {
+    // CAVP AESAVS known answer tests, ECBGFSbox{128,192,256}.rsp
+    for (plaintext, ciphertext) in [
+        (
+            hex!("f34481ec3cc627bacd5dc3fb08f273e6"),
+            hex!("0336763e966d92595a567cc9ce537f5e"),
+        ),
+        (
+            hex!("9798c4640bad75c7c3227db910174e72"),
+            hex!("a9a1631bf4996954ebc093957b234589"),
+        ),
+        (
+            hex!("96ab5c2ff612d9dfaae8c31f30c42168"),
+            hex!("ff4f8391a6a40ca5b25d23bedd44a597"),
+        ),
+        (
+            hex!("6a118a874519e64e9963798a503f1d35"),
+            hex!("dc43be40be0e53712f7e2bf5ca707209"),
+        ),
+        (
+            hex!("cb9fceec81286ca3e989bd979b0cb284"),
+            hex!("92beedab1895a94faa69b632e5cc47ce"),
+        ),
+        (
+            hex!("b26aeb1874e47ca8358ff22378f09144"),
+            hex!("459264f4798f6a78bacb89c15ed3d601"),
+        ),
+        (
+            hex!("58c8e00b2631686d54eab84b91f0aca1"),
+            hex!("08a4e2efec8a8e3312ca7460b9040bbf"),
+        ),
+    ] {
+        check(aes128([0; 16]), plaintext, ciphertext);
+    }
+
+    for (plaintext, ciphertext) in [
+        (
+            hex!("1b077a6af4b7f98229de786d7516b639"),
+            hex!("275cfc0413d8ccb70513c3859b1d0f72"),
+        ),
+        (
+            hex!("9c2d8842e5f48f57648205d39a239af1"),
+            hex!("c9b8135ff1b5adc413dfd053b21bd96d"),
+        ),
+        (
+            hex!("bff52510095f518ecca60af4205444bb"),
+            hex!("4a3650c3371ce2eb35e389a171427440"),
+        ),
+        (
+            hex!("51719783d3185a535bd75adc65071ce1"),
+            hex!("4f354592ff7c8847d2d0870ca9481b7c"),
+        ),
+        (
+            hex!("26aa49dcfe7629a8901a69a9914e6dfd"),
+            hex!("d5e08bf9a182e857cf40b3a36ee248cc"),
+        ),
+        (
+            hex!("941a4773058224e1ef66d10e0a6ee782"),
+            hex!("067cd9d3749207791841562507fa9626"),
+        ),
+    ] {
+        check(aes192([0; 24]), plaintext, ciphertext);
+    }
+
+    for (plaintext, ciphertext) in [
+        (
+            hex!("014730f80ac625fe84f026c60bfd547d"),
+            hex!("5c9d844ed46f9885085e5d6a4f94c7d7"),
+        ),
+        (
+            hex!("0b24af36193ce4665f2825d7b4749c98"),
+            hex!("a9ff75bd7cf6613d3731c77c3b6d0c04"),
+        ),
+        (
+            hex!("761c1fe41a18acf20d241650611d90f1"),
+            hex!("623a52fcea5d443e48d9181ab32c7421"),
+        ),
+        (
+            hex!("8a560769d605868ad80d819bdba03771"),
+            hex!("38f2c7ae10612415d27ca190d27da8b4"),
+        ),
+        (
+            hex!("91fbef2d15a97816060bee1feaa49afe"),
+            hex!("1bc704f1bce135ceb810341b216d7abe"),
+        ),
+    ] {
+        check(aes256([0; 32]), plaintext, ciphertext);
+    }
+}
+
+#[test]
+fn aes_cavp_keysbox() {
+    // CAVP ECBKeySbox128.rsp and ECBKeySbox192.rsp, COUNT = 0
+    check(
+        aes128(hex!("10a58869d74be5a374cf867cfb473859")),
+        [0; 16],
+        hex!("6d251e6944b051e04eaa6fb4dbf78465"),
+    );
+    check(
+        aes192(hex!("e9f065d7c13573587f7875357dfbb16c53489f6a4bd0f7cd")),
+        [0; 16],
+        hex!("0956259c9cd5cfd0181cca53380cde06"),
+    );
+}
+
+// the i-th input of the VarTxt and VarKey files has its i + 1 leading bits set
+fn leading_ones<const N: usize>(count: usize) -> [u8; N] {
+    let mut bytes = [0; N];
+    for bit in 0..count {
+        bytes[bit / 8] |= 0x80 >> (bit % 8);
+    }
+    bytes
+}
+
+#[test]
+fn aes_cavp_vartxt_varkey() {
+    // CAVP ECBVarTxt{128,192,256}.rsp and ECBVarKey{128,192,256}.rsp,
+    // first and last entries
+    check(
+        aes128([0; 16]),
+        leading_ones(1),
+        hex!("3ad78e726c1ec02b7ebfe92b23d9ec34"),
+    );
+    check(
+        aes128([0; 16]),
+        leading_ones(128),
+        hex!("3f5b8cc9ea855a0afa7347d23e8d664e"),
+    );
+    check(
+        aes192([0; 24]),
+        leading_ones(1),
+        hex!("6cd02513e8d4dc986b4afe087a60bd0c"),
+    );
+    check(
+        aes256([0; 32]),
+        leading_ones(1),
+        hex!("ddc6bf790c15760d8d9aeb6f9a75fd4e"),
+    );
+
+    check(
+        aes128(leading_ones(1)),
+        [0; 16],
+        hex!("0edd33d3c621e546455bd8ba1418bec8"),
+    );
+    check(
+        aes128(leading_ones(128)),
+        [0; 16],
+        hex!("a1f6258c877d5fcd8964484538bfc92c"),
+    );
+    check(
+        aes192(leading_ones(1)),
+        [0; 16],
+        hex!("de885dc87f5a92594082d02cc1e1b42c"),
+    );
+    check(
+        aes256(leading_ones(1)),
+        [0; 16],
+        hex!("e35a6dcb19b201a01ebcfa8aa22b5759"),
+    );
+}
+
+#[test]
+fn aes_cavp_monte_carlo_inner_loop() {
+    // CAVP ECBMCT128.rsp COUNT = 0: 1000 chained encryptions under one key
+    for cipher in aes128(hex!("139a35422f1d61de3c91787fe0507afd")) {
+        let mut block = hex!("b9145a768b7dc489a096b546f43b231f");
+        for _ in 0..1000 {
+            cipher.encrypt_block(&mut block);
+        }
+        assert_eq!(block, hex!("d7c3ffac9031238650901e157364c386"));
+
+        for _ in 0..1000 {
+            cipher.decrypt_block(&mut block);
+        }
+        assert_eq!(block, hex!("b9145a768b7dc489a096b546f43b231f"));
+    }
+}
+
+#[test]
+fn aes_multi_block_matches_single_block() {
+    let [default, soft] = aes256(hex!(
+        "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4"
+    ));
+
+    // covers partial and full four-block batches of the software backend
+    for len in 0..=9 {
+        let mut blocks = [[0_u8; 16]; 9];
+        for (i, block) in blocks.iter_mut().enumerate() {
+            *block = [i as u8 * 17; 16];
+        }
+        let blocks = &mut blocks[..len];
+
+        let mut expected = blocks.to_vec();
+        for block in &mut expected {
+            soft.encrypt_block(block);
+        }
+
+        let mut batch = blocks.to_vec();
+        soft.encrypt_blocks(&mut batch);
+        assert_eq!(batch, expected);
+
+        let mut batch = blocks.to_vec();
+        default.encrypt_blocks(&mut batch);
+        assert_eq!(batch, expected);
+
+        default.decrypt_blocks(&mut batch);
+        soft.decrypt_blocks(&mut expected);
+        assert_eq!(batch, blocks);
+        assert_eq!(expected, blocks);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn aes_backend_detection() {
+    use purecrypt::aes::Backend;
+
+    let key = Key128::default();
+    let expected = match std::arch::is_x86_feature_detected!("aes") {
+        true => Backend::AesNi,
+        false => Backend::Soft,
+    };
+
+    assert_eq!(Aes128::new(&key).backend(), expected);
+    assert_eq!(Aes128::new_soft(&key).backend(), Backend::Soft);
+}
@@ -0,0 +1,387 @@
+use hex_literal::hex;
+use purecrypt::aead::Aead;
+use purecrypt::aes::{Key128, Key256};
+use purecrypt::aes_gcm::{Aes128Gcm, Aes256Gcm, AesGcm, Error, Nonce, Tag};
+use wycheproof::TestResult;
+use wycheproof::aead::{TestName, TestSet};
+
+// both the detected backends and the software ones
+fn aes128(key: [u8; 16]) -> Vec<Aes128Gcm> {
+    let key = Key128::new(key);
+    vec![Aes128Gcm::new(&key), Aes128Gcm::new_soft(&key)]
+}
+
+fn aes256(key: [u8; 32]) -> Vec<Aes256Gcm> {
+    let key = Key256::new(key);
+    vec![Aes256Gcm::new(&key), Aes256Gcm::new_soft(&key)]
+}
+
+fn check<C: purecrypt::aes::BlockCipher>(
+    ciphers: Vec<AesGcm<C>>,
+    iv: &[u8],
+    aad: &[u8],
+    plaintext: &[u8],
+    ciphertext: &[u8],
+    tag: [u8; 16],
+) {
+    for cipher in ciphers {
+        let mut buffer = plaintext.to_vec();
+        let computed = cipher
+            .encrypt_in_place_detached_with_iv(iv, aad, &mut buffer)
+            .unwrap();
+        assert_eq!(buffer, ciphertext);
+        assert_eq!(computed, Tag::new(tag));
+
+        cipher
+            .decrypt_in_place_detached_with_iv(iv, aad, &mut buffer, &computed)
+            .unwrap();
+        assert_eq!(buffer, plaintext);
+
+        if let Ok(nonce) = <[u8; 12]>::try_from(iv) {
+            let nonce = Nonce::new(nonce);
+            let sealed = cipher.seal(&nonce, aad, plaintext).unwrap();
+            assert_eq!(&sealed[..plaintext.len()], ciphertext);
+            assert_eq!(&sealed[plaintext.len()..], &tag);
+            assert_eq!(cipher.open(&nonce, aad, &sealed).unwrap(), plaintext);
+        }
+    }
+}
+
+#[test]
+fn aes_gcm_spec_vectors() {
+    // test cases from the GCM specification submitted to NIST, McGrew and
+    // Viega, "The Galois/Counter Mode of Operation", appendix B; cases 5, 6,
+    // 17 and 18 use 64-bit and 480-bit IVs
+    // test case 1
+    check(
+        aes128(hex!("00000000000000000000000000000000")),
+        &hex!("000000000000000000000000"),
+        &[],
+        &[],
+        &[],
+        hex!("58e2fccefa7e3061367f1d57a4e7455a"),
+    );
+
+    // test case 2
+    check(
+        aes128(hex!("00000000000000000000000000000000")),
+        &hex!("000000000000000000000000"),
+        &[],
+        &hex!("00000000000000000000000000000000"),
+        &hex!("0388dace60b6a392f328c2b971b2fe78"),
+        hex!("ab6e47d42cec13bdf53a67b21257bddf"),
+    );
+
+    // test case 3
+    check(
+        aes128(hex!("feffe9928665731c6d6a8f9467308308")),
+        &hex!("cafebabefacedbaddecaf888"),
+        &[],
+        &hex!(
+            "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a72"
+            "1c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b391aafd255"
+        ),
+        &hex!(
+            "42831ec2217774244b7221b784d0d49ce3aa212f2c02a4e035c17e2329aca12e"
+            "21d514b25466931c7d8f6a5aac84aa051ba30b396a0aac973d58e091473f5985"
+        ),
+        hex!("4d5c2af327cd64a62cf35abd2ba6fab4"),
+    );
+
+    // test case 4
+    check(
+        aes128(hex!("feffe9928665731c6d6a8f9467308308")),
+        &hex!("cafebabefacedbaddecaf888"),
+        &hex!("feedfacedeadbeeffeedfacedeadbeefabaddad2"),
+        &hex!(
+            "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a72"
+            "1c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39"
+        ),
+        &hex!(
+            "42831ec2217774244b7221b784d0d49ce3aa212f2c02a4e035c17e2329aca12e"
+            "21d514b25466931c7d8f6a5aac84aa051ba30b396a0aac973d58e091"
+        ),
+        hex!("5bc94fbc3221a5db94fae95ae7121a47"),
+    );
+
+    // test case 5
+    check(
+        aes128(hex!("feffe9928665731c6d6a8f9467308308")),
+        &hex!("cafebabefacedbad"),
+        &hex!("feedfacedeadbeeffeedfacedeadbeefabaddad2"),
+        &hex!(
+            "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a72"
+            "1c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39"
+        ),
+        &hex!(
+            "61353b4c2806934a777ff51fa22a4755699b2a714fcdc6f83766e5f97b6c7423"
+            "73806900e49f24b22b097544d4896b424989b5e1ebac0f07c23f4598"
+        ),
+        hex!("3612d2e79e3b0785561be14aaca2fccb"),
+    );
+
+    // test case 6
+    check(
+        aes128(hex!("feffe9928665731c6d6a8f9467308308")),
+        &hex!(
+            "9313225df88406e555909c5aff5269aa6a7a9538534f7da1e4c303d2a318a728"
+            "c3c0c95156809539fcf0e2429a6b525416aedbf5a0de6a57a637b39b"
+        ),
+        &hex!("feedfacedeadbeeffeedfacedeadbeefabaddad2"),
+        &hex!(
+            "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a72"
+            "1c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39"
+        ),
+        &hex!(
+            "8ce24998625615b603a033aca13fb894be9112a5c3a211a8ba262a3cca7e2ca7"
+            "01e4a9a4fba43c90ccdcb281d48c7c6fd62875d2aca417034c34aee5"
+        ),
+        hex!("619cc5aefffe0bfa462af43c1699d050"),
+    );
+
+    // test case 13
+    check(
+        aes256(hex!(
+            "0000000000000000000000000000000000000000000000000000000000000000"
+        )),
+        &hex!("000000000000000000000000"),
+        &[],
+        &[],
+        &[],
+        hex!("530f8afbc74536b9a963b4f1c4cb738b"),
+    );
+
+    // test case 14
+    check(
+        aes256(hex!(
+            "0000000000000000000000000000000000000000000000000000000000000000"
+        )),
+        &hex!("000000000000000000000000"),
+        &[],
+        &hex!("00000000000000000000000000000000"),
+        &hex!("cea7403d4d606b6e074ec5d3baf39d18"),
+        hex!("d0d1c8a799996bf0265b98b5d48ab919"),
+    );
+
+    // test case 15
+    check(
+        aes256(hex!(
+            "feffe9928665731c6d6a8f9467308308feffe9928665731c6d6a8f9467308308"
+        )),
+        &hex!("cafebabefacedbaddecaf888"),
+        &[],
+        &hex!(
+            "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a72"
+            "1c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b391aafd255"
+        ),
+        &hex!(
+            "522dc1f099567d07f47f37a32a84427d643a8cdcbfe5c0c97598a2bd2555d1aa"
+            "8cb08e48590dbb3da7b08b1056828838c5f61e6393ba7a0abcc9f662898015ad"
+        ),
+        hex!("b094dac5d93471bdec1a502270e3cc6c"),
+    );
+
+    // test case 16
+    check(
+        aes256(hex!(
+            "feffe9928665731c6d6a8f9467308308feffe9928665731c6d6a8f9467308308"
+        )),
+        &hex!("cafebabefacedbaddecaf888"),
+        &hex!("feedfacedeadbeeffeedfacedeadbeefabaddad2"),
+        &hex!(
+            "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a72"
+            "1c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39"
+        ),
+        &hex!(
+            "522dc1f099567d07f47f37a32a84427d643a8cdcbfe5c0c97598a2bd2555d1aa"
+            "8cb08e48590dbb3da7b08b1056828838c5f61e6393ba7a0abcc9f662"
+        ),
+        hex!("76fc6ece0f4e1768cddf8853bb2d551b"),
+    );
+
+    // test case 17
+    check(
+        aes256(hex!(
+            "feffe9928665731c6d6a8f9467308308feffe9928665731c6d6a8f9467308308"
+        )),
+        &hex!("cafebabefacedbad"),
+        &hex!("feedfacedeadbeeffeedfacedeadbeefabaddad2"),
+        &hex!(
+            "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a72"
+            "1c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39"
+        ),
+        &hex!(
+            "c3762df1ca787d32ae47c13bf19844cbaf1ae14d0b976afac52ff7d79bba9de0"
+            "feb582d33934a4f0954cc2363bc73f7862ac430e64abe499f47c9b1f"
+        ),
+        hex!("3a337dbf46a792c45e454913fe2ea8f2"),
+    );
+
+    // test case 18
+    check(
+        aes256(hex!(
+            "feffe9928665731c6d6a8f9467308308feffe9928665731c6d6a8f9467308308"
+        )),
+        &hex!(
+            "9313225df88406e555909c5aff5269aa6a7a9538534f7da1e4c303d2a318a728"
+            "c3c0c95156809539fcf0e2429a6b525416aedbf5a0de6a57a637b39b"
+        ),
+        &hex!("feedfacedeadbeeffeedfacedeadbeefabaddad2"),
+        &hex!(
+            "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a72"
+            "1c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39"
+        ),
+        &hex!(
+            "5a8def2f0c9e53f1f75d7853659e2a20eeb2b22aafde6419a058ab4f6f746bf4"
+            "0fc0c3b780f244452da3ebf1c5d82cdea2418997200ef82e44ae7e3f"
+        ),
+        hex!("a44a8266ee1c8eb0c8b5d4cf5ae9f19a"),
+    );
+}
+
+#[test]
+fn aes_gcm_rejects_forgeries() {
+    let cipher = Aes256Gcm::new(&Key256::new([0x42; 32]));
+    let nonce = Nonce::new([7; 12]);
+
+    let sealed = cipher.seal(&nonce, b"header", b"attack at dawn").unwrap();
+
+    for i in 0..sealed.len() {
+        let mut forged = sealed.clone();
+        forged[i] ^= 0x01;
+        assert_eq!(
+            cipher.open(&nonce, b"header", &forged),
+            Err(Error::InvalidTag)
+        );
+    }
+
+    assert_eq!(
+        cipher.open(&nonce, b"footer", &sealed),
+        Err(Error::InvalidTag)
+    );
+    assert_eq!(
+        cipher.open(&nonce, b"header", &sealed[..15]),
+        Err(Error::InvalidTag)
+    );
+
+    // a failed decryption leaves the buffer as it was
+    let mut buffer = sealed[..14].to_vec();
+    let tag = Tag::new([0; 16]);
+    assert_eq!(
+        cipher.decrypt_in_place_detached(&nonce, b"header", &mut buffer, &tag),
+        Err(Error::InvalidTag)
+    );
+    assert_eq!(buffer, &sealed[..14]);
+
+    assert_eq!(
+        cipher.encrypt_in_place_detached_with_iv(&[], b"", &mut []),
+        Err(Error::InvalidIvLength)
+    );
+}
+
+#[test]
+fn aes_gcm_backends_agree() {
+    let key = Key128::new([0x24; 16]);
+    let (detected, soft) = (Aes128Gcm::new(&key), Aes128Gcm::new_soft(&key));
+
+    let message: Vec<u8> = (0..1000).map(|i| i as u8).collect();
+
+    for len in [0, 1, 15, 16, 17, 127, 128, 129, 1000] {
+        for iv_len in [1, 12, 16, 17] {
+            let iv = &message[..iv_len];
+            let aad = &message[len / 2..len];
+
+            let mut a = message[..len].to_vec();
+            let mut b = message[..len].to_vec();
+            let tag_a = detected
+                .encrypt_in_place_detached_with_iv(iv, aad, &mut a)
+                .unwrap();
+            let tag_b = soft
+                .encrypt_in_place_detached_with_iv(iv, aad, &mut b)
+                .unwrap();
+
+            assert_eq!(a, b);
+            assert_eq!(tag_a, tag_b);
+        }
+    }
+}
+
+#[test]
+fn aes_gcm_wycheproof() {
+    // Wycheproof AES-GCM vectors, 128-bit tags and 128/256-bit keys:
+    // https://github.com/C2SP/wycheproof/blob/main/testvectors_v1/aes_gcm_test.json
+    let test_set = TestSet::load(TestName::AesGcm).unwrap();
+    let mut checked = 0;
+
+    for group in test_set.test_groups {
+        if group.tag_size != 128 || ![128, 256].contains(&group.key_size) {
+            continue;
+        }
+
+        for test in group.tests {
+            let tag = Tag::new(test.tag.as_slice().try_into().unwrap());
+
+            let (ciphertext, decrypted) = match group.key_size {
+                128 => {
+                    let cipher =
+                        Aes128Gcm::new(&Key128::new(test.key.as_slice().try_into().unwrap()));
+                    run(&cipher, &test.nonce, &test.aad, &test.pt, &test.ct, &tag)
+                }
+                _ => {
+                    let cipher =
+                        Aes256Gcm::new(&Key256::new(test.key.as_slice().try_into().unwrap()));
+                    run(&cipher, &test.nonce, &test.aad, &test.pt, &test.ct, &tag)
+                }
+            };
+
+            match test.result {
+                TestResult::Valid | TestResult::Acceptable => {
+                    assert_eq!(
+                        ciphertext.as_deref(),
+                        Some(test.ct.as_slice()),
+                        "tcId {}",
+                        test.tc_id
+                    );
+                    assert_eq!(
+                        decrypted.as_deref(),
+                        Some(test.pt.as_slice()),
+                        "tcId {}",
+                        test.tc_id
+                    );
+                }
+                TestResult::Invalid => {
+                    assert_eq!(decrypted, None, "tcId {}", test.tc_id);
+                }
+            }
+
+            checked += 1;
+        }
+    }
+
+    assert!(checked > 200);
+}
+
+// returns the computed ciphertext when its tag matches and the decryption of
+// the expected ciphertext
+fn run<C: purecrypt::aes::BlockCipher>(
+    cipher: &AesGcm<C>,
+    iv: &[u8],
+    aad: &[u8],
+    plaintext: &[u8],
+    ciphertext: &[u8],
+    tag: &Tag,
+) -> (Option<Vec<u8>>, Option<Vec<u8>>) {
+    let mut buffer = plaintext.to_vec();
+    let encrypted = match cipher.encrypt_in_place_detached_with_iv(iv, aad, &mut buffer) {
+        Ok(computed) if computed == *tag => Some(buffer),
+        _ => None,
+    };
+
+    let mut buffer = ciphertext.to_vec();
+    let decrypted = cipher
+        .decrypt_in_place_detached_with_iv(iv, aad, &mut buffer, tag)
+        .ok()
+        .map(|_| buffer);
+
+    (encrypted, decrypted)
+}
@@ -0,0 +1,371 @@
+use hex_literal::hex;
+use purecrypt::aead::Aead;
+use purecrypt::aes::{BlockCipher, Key128, Key256};
+use purecrypt::aes_gcm_siv::{Aes128GcmSiv, Aes256GcmSiv, AesGcmSiv, Error, Nonce, Tag};
+use wycheproof::TestResult;
+use wycheproof::aead::{TestName, TestSet};
+
+// both the detected backends and the software ones
+fn aes128(key: [u8; 16]) -> Vec<Aes128GcmSiv> {
+    let key = Key128::new(key);
+    vec![Aes128GcmSiv::new(&key), Aes128GcmSiv::new_soft(&key)]
+}
+
+fn aes256(key: [u8; 32]) -> Vec<Aes256GcmSiv> {
+    let key = Key256::new(key);
+    vec![Aes256GcmSiv::new(&key), Aes256GcmSiv::new_soft(&key)]
+}
+
+fn check<C>(
+    ciphers: Vec<AesGcmSiv<C>>,
+    nonce: [u8; 12],
+    aad: &[u8],
+    plaintext: &[u8],
+    ciphertext: &[u8],
+    tag: [u8; 16],
+) where
+    C: BlockCipher,
+    C::Key: Default + AsMut<[u8]>,
+{
+    let nonce = Nonce::new(nonce);
+
+    for cipher in ciphers {
+        let mut buffer = plaintext.to_vec();
+        let computed = cipher
+            .encrypt_in_place_detached(&nonce, aad, &mut buffer)
+            .unwrap();
+        assert_eq!(buffer, ciphertext);
+        assert_eq!(computed, Tag::new(tag));
+
+        cipher
+            .decrypt_in_place_detached(&nonce, aad, &mut buffer, &computed)
+            .unwrap();
+        assert_eq!(buffer, plaintext);
+
+        let sealed = cipher.seal(&nonce, aad, plaintext).unwrap();
+        assert_eq!(&sealed[..plaintext.len()], ciphertext);
+        assert_eq!(&sealed[plaintext.len()..], &tag);
+        assert_eq!(cipher.open(&nonce, aad, &sealed).unwrap(), plaintext);
+    }
+}
+
+#[test]
+fn aes_128_gcm_siv_rfc8452_vectors() {
+    // RFC 8452 appendix C.1
+    check(
+        aes128(hex!("01000000000000000000000000000000")),
+        hex!("030000000000000000000000"),
+        &[],
+        &[],
+        &[],
+        hex!("dc20e2d83f25705bb49e439eca56de25"),
+    );
+    check(
+        aes128(hex!("01000000000000000000000000000000")),
+        hex!("030000000000000000000000"),
+        &[],
+        &hex!("0100000000000000"),
+        &hex!("b5d839330ac7b786"),
+        hex!("578782fff6013b815b287c22493a364c"),
+    );
+    check(
+        aes128(hex!("01000000000000000000000000000000")),
+        hex!("030000000000000000000000"),
+        &[],
+        &hex!("01000000000000000000000000000000" "02000000000000000000000000000000"),
+        &hex!("84e07e62ba83a6585417245d7ec413a9" "fe427d6315c09b57ce45f2e3936a9445"),
+        hex!("1a8e45dcd4578c667cd86847bf6155ff"),
+    );
+    check(
+        aes128(hex!("01000000000000000000000000000000")),
+        hex!("030000000000000000000000"),
+        &hex!("01"),
+        &hex!("0200000000000000"),
+        &hex!("1e6daba35669f427"),
+        hex!("3b0a1a2560969cdf790d99759abd1508"),
+    );
+    check(
+        aes128(hex!("01000000000000000000000000000000")),
+        hex!("030000000000000000000000"),
+        &hex!("01"),
+        &hex!(
+            "02000000000000000000000000000000"
+            "03000000000000000000000000000000"
+            "04000000000000000000000000000000"
+        ),
+        &hex!(
+            "50c8303ea93925d64090d07bd109dfd9"
+            "515a5a33431019c17d93465999a8b005"
+            "3201d723120a8562b838cdff25bf9d1e"
+        ),
+        hex!("6a8cc3865f76897c2e4b245cf31c51f2"),
+    );
+    check(
+        aes128(hex!("01000000000000000000000000000000")),
+        hex!("030000000000000000000000"),
+        &hex!("01000000000000000000000000000000" "0200"),
+        &hex!("03000000000000000000000000000000" "04000000"),
+        &hex!("6bb0fecf5ded9b77f902c7d5da236a43" "91dd0297"),
+        hex!("24afc9805e976f451e6d87f6fe106514"),
+    );
+    check(
+        aes128(hex!("aedb64a6c590bc84d1a5e269e4b47801")),
+        hex!("afc0577e34699b9e671fdd4f"),
+        &hex!("fc880c94a95198874296"),
+        &hex!("bdc66f146545"),
+        &hex!("bb93a3e34d3c"),
+        hex!("d6a9c45545cfc11f03ad743dba20f966"),
+    );
+    check(
+        aes128(hex!("bde3b2f204d1e9f8b06bc47f9745b3d1")),
+        hex!("ae06556fb6aa7890bebc18fe"),
+        &hex!("1860f762ebfbd08284e421702de0de18" "baa9c9596291b08466f37de21c7f"),
+        &hex!("6b3db4da3d57aa94842b9803a96e07fb" "6de7"),
+        &hex!("6298b296e24e8cc35dce0bed484b7f30" "d580"),
+        hex!("3e377094f04709f64d7b985310a4db84"),
+    );
+}
+
+#[test]
+fn aes_256_gcm_siv_rfc8452_vectors() {
+    // RFC 8452 appendix C.2
+    let key = hex!("01000000000000000000000000000000" "00000000000000000000000000000000");
+
+    check(
+        aes256(key),
+        hex!("030000000000000000000000"),
+        &[],
+        &[],
+        &[],
+        hex!("07f5f4169bbf55a8400cd47ea6fd400f"),
+    );
+    check(
+        aes256(key),
+        hex!("030000000000000000000000"),
+        &[],
+        &hex!("01000000000000000000000000000000" "02000000000000000000000000000000"),
+        &hex!("4a6a9db4c8c6549201b9edb53006cba8" "21ec9cf850948a7c86c68ac7539d027f"),
+        hex!("e819e63abcd020b006a976397632eb5d"),
+    );
+    check(
+        aes256(key),
+        hex!("030000000000000000000000"),
+        &hex!("01"),
+        &hex!(
+            "02000000000000000000000000000000"
+            "03000000000000000000000000000000"
+            "04000000000000000000000000000000"
+        ),
+        &hex!(
+            "c67a1f0f567a5198aa1fcc8e3f213143"
+            "36f7f51ca8b1af61feac35a86416fa47"
+            "fbca3b5f749cdf564527f2314f42fe25"
+        ),
+        hex!("03332742b228c647173616cfd44c54eb"),
+    );
+    check(
+        aes256(hex!(
+            "e66021d5eb8e4f4066d4adb9c33560e4"
+            "f46e44bb3da0015c94f7088736864200"
+        )),
+        hex!("e0eaf5284d884a0e77d31646"),
+        &[],
+        &[],
+        &[],
+        hex!("169fbb2fbf389a995f6390af22228a62"),
+    );
+    check(
+        aes256(hex!(
+            "9745b3d1ae06556fb6aa7890bebc18fe"
+            "6b3db4da3d57aa94842b9803a96e07fb"
+        )),
+        hex!("6de71860f762ebfbd08284e4"),
+        &hex!("f37de21c7ff901cfe8a69615a93fdf7a" "98cad481796245709f"),
+        &hex!("21702de0de18baa9c9596291b08466"),
+        &hex!("793576dfa5c0f88729a7ed3c2f1bff"),
+        hex!("b3080d28f6ebb5d3648ce97bd5ba67fd"),
+    );
+}
+
+#[test]
+fn aes_gcm_siv_counter_wrap() {
+    // RFC 8452 appendix C.3, the tag starts the counter at 2^32 - 1
+    check(
+        aes256([0; 32]),
+        [0; 12],
+        &[],
+        &hex!("00000000000000000000000000000000" "4db923dc793ee6497c76dcc03a98e108"),
+        &hex!("f3f80f2cf0cb2dd9c5984fcda908456c" "c537703b5ba70324a6793a7bf218d3ea"),
+        hex!("ffffffff000000000000000000000000"),
+    );
+    check(
+        aes256([0; 32]),
+        [0; 12],
+        &[],
+        &hex!("eb3640277c7ffd1303c7a542d02d3e4c" "0000000000000000"),
+        &hex!("18ce4f0b8cb4d0cac65fea8f79257b20" "888e53e72299e56d"),
+        hex!("ffffffff000000000000000000000000"),
+    );
+}
+
+#[test]
+fn aes_gcm_siv_nonce_reuse() {
+    let cipher = Aes128GcmSiv::new(&Key128::new([0x42; 16]));
+    let nonce = Nonce::new([7; 12]);
+
+    // a repeated nonce only leaks equality of whole messages
+    let a = cipher.seal(&nonce, b"", b"attack at dawn").unwrap();
+    let b = cipher.seal(&nonce, b"", b"attack at dawn").unwrap();
+    let c = cipher.seal(&nonce, b"", b"attack at dusk").unwrap();
+
+    assert_eq!(a, b);
+    assert_ne!(a[..10], c[..10]);
+}
+
+#[test]
+fn aes_gcm_siv_rejects_forgeries() {
+    let cipher = Aes256GcmSiv::new(&Key256::new([0x11; 32]));
+    let nonce = Nonce::new([0x22; 12]);
+
+    let sealed = cipher.seal(&nonce, b"header", b"some payload").unwrap();
+
+    for i in 0..sealed.len() {
+        let mut forged = sealed.clone();
+        forged[i] ^= 0x80;
+        assert_eq!(
+            cipher.open(&nonce, b"header", &forged),
+            Err(Error::InvalidTag)
+        );
+    }
+
+    assert_eq!(
+        cipher.open(&Nonce::new([0x23; 12]), b"header", &sealed),
+        Err(Error::InvalidTag)
+    );
+    assert_eq!(
+        cipher.open(&nonce, b"footer", &sealed),
+        Err(Error::InvalidTag)
+    );
+    assert_eq!(
+        cipher.open(&nonce, b"header", &sealed[..15]),
+        Err(Error::InvalidTag)
+    );
+
+    // a failed decryption leaves the buffer as it was
+    let mut buffer = sealed[..12].to_vec();
+    let tag = Tag::new([0; 16]);
+    assert_eq!(
+        cipher.decrypt_in_place_detached(&nonce, b"header", &mut buffer, &tag),
+        Err(Error::InvalidTag)
+    );
+    assert_eq!(buffer, &sealed[..12]);
+}
+
+#[test]
+fn aes_gcm_siv_backends_agree() {
+    let key = Key256::new([0x24; 32]);
+    let (detected, soft) = (Aes256GcmSiv::new(&key), Aes256GcmSiv::new_soft(&key));
+
+    let message: Vec<u8> = (0..1000).map(|i| i as u8).collect();
+    let nonce = Nonce::new([0x5a; 12]);
+
+    for len in [0, 1, 15, 16, 17, 127, 128, 129, 1000] {
+        let aad = &message[len / 2..len];
+
+        let mut a = message[..len].to_vec();
+        let mut b = message[..len].to_vec();
+        let tag_a = detected
+            .encrypt_in_place_detached(&nonce, aad, &mut a)
+            .unwrap();
+        let tag_b = soft.encrypt_in_place_detached(&nonce, aad, &mut b).unwrap();
+
+        assert_eq!(a, b);
+        assert_eq!(tag_a, tag_b);
+    }
+}
+
+#[test]
+fn aes_gcm_siv_wycheproof() {
+    // Wycheproof AES-GCM-SIV vectors, including counter overflow cases:
+    // https://github.com/C2SP/wycheproof/blob/main/testvectors_v1/aes_gcm_siv_test.json
+    let test_set = TestSet::load(TestName::AesGcmSiv).unwrap();
+    let mut checked = 0;
+
+    for group in test_set.test_groups {
+        if group.tag_size != 128 || group.nonce_size != 96 || ![128, 256].contains(&group.key_size)
+        {
+            continue;
+        }
+
+        for test in group.tests {
+            let nonce = Nonce::new(test.nonce.as_slice().try_into().unwrap());
+            let tag = Tag::new(test.tag.as_slice().try_into().unwrap());
+
+            let (ciphertext, decrypted) = match group.key_size {
+                128 => {
+                    let cipher =
+                        Aes128GcmSiv::new(&Key128::new(test.key.as_slice().try_into().unwrap()));
+                    run(&cipher, &nonce, &test.aad, &test.pt, &test.ct, &tag)
+                }
+                _ => {
+                    let cipher =
+                        Aes256GcmSiv::new(&Key256::new(test.key.as_slice().try_into().unwrap()));
+                    run(&cipher, &nonce, &test.aad, &test.pt, &test.ct, &tag)
+                }
+            };
+
+            match test.result {
+                TestResult::Valid | TestResult::Acceptable => {
+                    assert_eq!(
+                        ciphertext.as_deref(),
+                        Some(test.ct.as_slice()),
+                        "tcId {}",
+                        test.tc_id
+                    );
+                    assert_eq!(
+                        decrypted.as_deref(),
+                        Some(test.pt.as_slice()),
+                        "tcId {}",
+                        test.tc_id
+                    );
+                }
+                TestResult::Invalid => {
+                    assert_eq!(decrypted, None, "tcId {}", test.tc_id);
+                }
+            }
+
+            checked += 1;
+        }
+    }
+
+    assert!(checked > 150);
+}
+
+// returns the computed ciphertext when its tag matches and the decryption of
+// the expected ciphertext
+fn run<C>(
+    cipher: &AesGcmSiv<C>,
+    nonce: &Nonce,
+    aad: &[u8],
+    plaintext: &[u8],
+    ciphertext: &[u8],
+    tag: &Tag,
+) -> (Option<Vec<u8>>, Option<Vec<u8>>)
+where
+    C: BlockCipher,
+    C::Key: Default + AsMut<[u8]>,
+{
+    let mut buffer = plaintext.to_vec();
+    let encrypted = match cipher.encrypt_in_place_detached(nonce, aad, &mut buffer) {
+        Ok(computed) if computed == *tag => Some(buffer),
+        _ => None,
+    };
+
+    let mut buffer = ciphertext.to_vec();
+    let decrypted = cipher
+        .decrypt_in_place_detached(nonce, aad, &mut buffer, tag)
+        .ok()
+        .map(|_| buffer);
+
+    (encrypted, decrypted)
+}
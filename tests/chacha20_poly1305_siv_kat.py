@@ -0,0 +1,120 @@
+#!/usr/bin/env python3
+# Reference implementation of ChaCha20-Poly1305-SIV as described in
+# src/chacha20_poly1305_siv.rs, written independently of the crate with no
+# dependencies. Running it prints the known answers used by
+# tests/chacha20_poly1305_siv_kat.rs.
+
+MASK32 = 0xFFFFFFFF
+CONSTANTS = [0x61707865, 0x3320646E, 0x79622D32, 0x6B206574]
+
+
+def rotl(v, n):
+    return ((v << n) & MASK32) | (v >> (32 - n))
+
+
+def quarter_round(s, a, b, c, d):
+    s[a] = (s[a] + s[b]) & MASK32
+    s[d] = rotl(s[d] ^ s[a], 16)
+    s[c] = (s[c] + s[d]) & MASK32
+    s[b] = rotl(s[b] ^ s[c], 12)
+    s[a] = (s[a] + s[b]) & MASK32
+    s[d] = rotl(s[d] ^ s[a], 8)
+    s[c] = (s[c] + s[d]) & MASK32
+    s[b] = rotl(s[b] ^ s[c], 7)
+
+
+def words(data):
+    return [int.from_bytes(data[i : i + 4], "little") for i in range(0, len(data), 4)]
+
+
+def to_bytes(ws):
+    return b"".join(w.to_bytes(4, "little") for w in ws)
+
+
+def rounds(state):
+    s = list(state)
+    for _ in range(10):
+        quarter_round(s, 0, 4, 8, 12)
+        quarter_round(s, 1, 5, 9, 13)
+        quarter_round(s, 2, 6, 10, 14)
+        quarter_round(s, 3, 7, 11, 15)
+        quarter_round(s, 0, 5, 10, 15)
+        quarter_round(s, 1, 6, 11, 12)
+        quarter_round(s, 2, 7, 8, 13)
+        quarter_round(s, 3, 4, 9, 14)
+    return s
+
+
+def hchacha20(key, inp):
+    s = rounds(CONSTANTS + words(key) + words(inp))
+    return to_bytes(s[0:4] + s[12:16])
+
+
+def chacha20_block(key, counter, nonce):
+    state = CONSTANTS + words(key) + [counter] + words(nonce)
+    return to_bytes([(a + b) & MASK32 for a, b in zip(rounds(state), state)])
+
+
+def xchacha20(key, xnonce, data):
+    subkey = hchacha20(key, xnonce[:16])
+    nonce = bytes(4) + xnonce[16:]
+    out = bytearray()
+    for i in range(0, len(data), 64):
+        block = chacha20_block(subkey, i // 64, nonce)
+        out += bytes(a ^ b for a, b in zip(data[i : i + 64], block))
+    return bytes(out)
+
+
+def poly1305(key, msg):
+    r = int.from_bytes(key[:16], "little") & 0x0FFFFFFC0FFFFFFC0FFFFFFC0FFFFFFF
+    s = int.from_bytes(key[16:], "little")
+    p = (1 << 130) - 5
+    acc = 0
+    for i in range(0, len(msg), 16):
+        block = msg[i : i + 16] + b"\x01"
+        acc = (acc + int.from_bytes(block, "little")) * r % p
+    return ((acc + s) % (1 << 128)).to_bytes(16, "little")
+
+
+def pad16(data):
+    return data + bytes(-len(data) % 16)
+
+
+def seal(key, nonce, aad, msg):
+    nonce = nonce if nonce is not None else bytes(24)
+    subkeys = xchacha20(key, nonce, bytes(96))
+    r, mac_key, enc_key = subkeys[:16], subkeys[32:64], subkeys[64:96]
+
+    lengths = len(aad).to_bytes(8, "little") + len(msg).to_bytes(8, "little")
+    h = poly1305(r + bytes(16), pad16(aad) + pad16(msg) + lengths)
+    tag = hchacha20(mac_key, h)[:16]
+
+    return xchacha20(enc_key, tag + bytes(8), msg), tag
+
+
+def pattern(n, start=0):
+    return bytes((start + i) % 256 for i in range(n))
+
+
+CASES = [
+    # key, nonce (None is deterministic), aad, plaintext
+    (bytes(32), None, b"", b""),
+    (bytes(32), bytes(24), b"", b""),
+    (pattern(32), None, b"", b"hello"),
+    (pattern(32), pattern(24, 0x40), b"", pattern(16)),
+    (pattern(32), pattern(24, 0x40), pattern(1, 0xA0), pattern(15)),
+    (pattern(32, 0x80), pattern(24, 0x50), pattern(20, 0xA0), pattern(64)),
+    (pattern(32, 0x80), None, pattern(33, 0xA0), pattern(129, 0x10)),
+    (bytes(range(255, 223, -1)), pattern(24, 0xE0), b"header", pattern(300, 7)),
+]
+
+if __name__ == "__main__":
+    for key, nonce, aad, msg in CASES:
+        ct, tag = seal(key, nonce, aad, msg)
+        print("key   ", key.hex())
+        print("nonce ", nonce.hex() if nonce is not None else None)
+        print("aad   ", aad.hex())
+        print("pt    ", msg.hex())
+        print("ct    ", ct.hex())
+        print("tag   ", tag.hex())
+        print()
@@ -0,0 +1,227 @@
+use hex_literal::hex;
+use purecrypt::chacha20_poly1305_siv::{Aead, ChaCha20Poly1305Siv, Error, Key, Tag, XNonce};
+
+// the ciphertext followed by the tag, `None` encrypts deterministically
+fn seal_optional(
+    cipher: &ChaCha20Poly1305Siv,
+    nonce: Option<&XNonce>,
+    aad: &[u8],
+    plaintext: &[u8],
+) -> Vec<u8> {
+    let mut buffer = plaintext.to_vec();
+    let tag = cipher
+        .encrypt_in_place_detached(nonce, aad, &mut buffer)
+        .unwrap();
+    buffer.extend_from_slice(tag.bytes());
+
+    buffer
+}
+
+fn check(
+    key: [u8; 32],
+    nonce: Option<[u8; 24]>,
+    aad: &[u8],
+    plaintext: &[u8],
+    ciphertext: &[u8],
+    tag: [u8; 16],
+) {
+    let cipher = ChaCha20Poly1305Siv::new(&Key::new(key));
+    let nonce = nonce.map(XNonce::new);
+    let nonce = nonce.as_ref();
+
+    let mut buffer = plaintext.to_vec();
+    let computed = cipher
+        .encrypt_in_place_detached(nonce, aad, &mut buffer)
+        .unwrap();
+    assert_eq!(buffer, ciphertext);
+    assert_eq!(computed, Tag::new(tag));
+
+    cipher
+        .decrypt_in_place_detached(nonce, aad, &mut buffer, &computed)
+        .unwrap();
+    assert_eq!(buffer, plaintext);
+
+    // seal and open always take a nonce, the omitted one is all zeros
+    let nonce = nonce.cloned().unwrap_or_default();
+    let sealed = cipher.seal(&nonce, aad, plaintext).unwrap();
+    assert_eq!(&sealed[..plaintext.len()], ciphertext);
+    assert_eq!(&sealed[plaintext.len()..], &tag);
+    assert_eq!(cipher.open(&nonce, aad, &sealed).unwrap(), plaintext);
+}
+
+#[test]
+fn chacha20_poly1305_siv_known_answers() {
+    // generated and cross-checked with the independent reference in
+    // tests/chacha20_poly1305_siv_kat.py
+    check(
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+        None,
+        &[],
+        &[],
+        &[],
+        hex!("45bdd40af0b031d3ac796a3f629e8b7d"),
+    );
+    check(
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+        Some(hex!("000000000000000000000000000000000000000000000000")),
+        &[],
+        &[],
+        &[],
+        hex!("45bdd40af0b031d3ac796a3f629e8b7d"),
+    );
+    check(
+        hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        None,
+        &[],
+        &hex!("68656c6c6f"),
+        &hex!("38a393f9e4"),
+        hex!("4d50d1e98805263ccf293160aef28501"),
+    );
+    check(
+        hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        Some(hex!("404142434445464748494a4b4c4d4e4f5051525354555657")),
+        &[],
+        &hex!("000102030405060708090a0b0c0d0e0f"),
+        &hex!("d2d84082171704a8d6f5a4adae120236"),
+        hex!("e77350f549b15481743b36c56645b0ce"),
+    );
+    check(
+        hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        Some(hex!("404142434445464748494a4b4c4d4e4f5051525354555657")),
+        &hex!("a0"),
+        &hex!("000102030405060708090a0b0c0d0e"),
+        &hex!("c411cdf8651a10ed3fb71aad0a5dee"),
+        hex!("cc369d973f67a81560134cbe42df9d30"),
+    );
+    check(
+        hex!("808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f"),
+        Some(hex!("505152535455565758595a5b5c5d5e5f6061626364656667")),
+        &hex!("a0a1a2a3a4a5a6a7a8a9aaabacadaeafb0b1b2b3"),
+        &hex!(
+            "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+            "202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"
+        ),
+        &hex!(
+            "581f9801c1cb6a6d3ccd9b6ea02bf70bf9e8badfd5541565df37306ff74cce11"
+            "22fbefb11aa38844f7f758b1c293fb269d697f0013a83b0c715ff28e158df864"
+        ),
+        hex!("9c6126cac9834a1f8568558b235d8ae0"),
+    );
+    check(
+        hex!("808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f"),
+        None,
+        &hex!(
+            "a0a1a2a3a4a5a6a7a8a9aaabacadaeafb0b1b2b3b4b5b6b7b8b9babbbcbdbebf"
+            "c0"
+        ),
+        &hex!(
+            "101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f"
+            "303132333435363738393a3b3c3d3e3f404142434445464748494a4b4c4d4e4f"
+            "505152535455565758595a5b5c5d5e5f606162636465666768696a6b6c6d6e6f"
+            "707172737475767778797a7b7c7d7e7f808182838485868788898a8b8c8d8e8f"
+            "90"
+        ),
+        &hex!(
+            "3c3733b0b5250a7adca8d43c2de3ec5d6116074e37512ad37fa5f2af172afba7"
+            "a720e06b1ce0fd9ce3d038fab284bfbe98c2bd4c13e2f95fe97f08b157fb50c8"
+            "92d71e50d036617c067242d291fcdc58369da0d2d2c20d6694d15d5fc1faec55"
+            "01a314acf0ada5d91ad2bc38abc756ba469c10a85686f7b1909c5bda6fb4329c"
+            "f2"
+        ),
+        hex!("8a5470ecd3a269bf66e3e91ac8d11bcc"),
+    );
+    check(
+        hex!("fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0efeeedecebeae9e8e7e6e5e4e3e2e1e0"),
+        Some(hex!("e0e1e2e3e4e5e6e7e8e9eaebecedeeeff0f1f2f3f4f5f6f7")),
+        &hex!("686561646572"),
+        &hex!(
+            "0708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20212223242526"
+            "2728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f40414243444546"
+            "4748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f60616263646566"
+            "6768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f80818283848586"
+            "8788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9fa0a1a2a3a4a5a6"
+            "a7a8a9aaabacadaeafb0b1b2b3b4b5b6b7b8b9babbbcbdbebfc0c1c2c3c4c5c6"
+            "c7c8c9cacbcccdcecfd0d1d2d3d4d5d6d7d8d9dadbdcdddedfe0e1e2e3e4e5e6"
+            "e7e8e9eaebecedeeeff0f1f2f3f4f5f6f7f8f9fafbfcfdfeff00010203040506"
+            "0708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20212223242526"
+            "2728292a2b2c2d2e2f303132"
+        ),
+        &hex!(
+            "caf9978718814dd265c2829fa98f0ff2cccd7fd9f838529f7713a9df1026389f"
+            "d9261bbe2fa6475b93dcc86593246158c4083813c278ab496831f743f8121573"
+            "cb30f8bc3b7a2888401af4820e7e839fea96d43fdcc0e4a18f0d67426061a8a4"
+            "b93519f1277d6bed54fefc624be57578ad34494c7ed3dc6ae281259828d0aba5"
+            "360f34fdc7157cd9c1f5024f325bd195c8cbb062b2983df4c83a727d4300a542"
+            "cf7c1bc7222fa3297a5cb9e471d5867f1dcb83202a8c1dc8cdd9b3283833e662"
+            "d5b5714588f98eb2e857e174231b8203204998e8555c630ca41637888af876d4"
+            "06afa71a462b822c58f20ad68721b8f467db8f8f262f1e3ac2706ccc839b7996"
+            "11cafff698b8efd709ba3fa973b0618394a77f436c45202ea8a8fb0cb176a65f"
+            "1efd891e60ead3a7d239141e"
+        ),
+        hex!("72cc23778995224929ebdfb5996c24cf"),
+    );
+}
+
+#[test]
+fn chacha20_poly1305_siv_deterministic() {
+    let cipher = ChaCha20Poly1305Siv::new(&Key::new([0x42; 32]));
+
+    // without a nonce equal inputs give equal outputs, which is the only
+    // thing a repeated nonce reveals
+    let a = seal_optional(&cipher, None, b"", b"attack at dawn");
+    let b = seal_optional(&cipher, None, b"", b"attack at dawn");
+    let c = seal_optional(&cipher, None, b"", b"attack at dusk");
+    let d = seal_optional(&cipher, None, b"ad", b"attack at dawn");
+
+    assert_eq!(a, b);
+    assert_ne!(a[..10], c[..10]);
+    assert_ne!(a, d);
+
+    // the omitted nonce is the all-zero nonce
+    assert_eq!(
+        cipher.seal(&XNonce::default(), b"", b"attack at dawn"),
+        Ok(a)
+    );
+
+    let nonce = XNonce::new([1; 24]);
+    assert_ne!(cipher.seal(&nonce, b"", b"attack at dawn").unwrap(), b);
+}
+
+#[test]
+fn chacha20_poly1305_siv_rejects_forgeries() {
+    let cipher = ChaCha20Poly1305Siv::new(&Key::new([0x11; 32]));
+    let nonce = XNonce::new([0x22; 24]);
+
+    let sealed = cipher.seal(&nonce, b"header", b"some payload").unwrap();
+
+    for i in 0..sealed.len() {
+        let mut forged = sealed.clone();
+        forged[i] ^= 0x01;
+        assert_eq!(
+            cipher.open(&nonce, b"header", &forged),
+            Err(Error::InvalidTag)
+        );
+    }
+
+    assert_eq!(
+        cipher.open(&XNonce::default(), b"header", &sealed),
+        Err(Error::InvalidTag)
+    );
+    assert_eq!(
+        cipher.open(&nonce, b"footer", &sealed),
+        Err(Error::InvalidTag)
+    );
+    assert_eq!(
+        cipher.open(&nonce, b"header", &sealed[..15]),
+        Err(Error::InvalidTag)
+    );
+
+    // a failed decryption leaves the buffer as it was
+    let mut buffer = sealed[..12].to_vec();
+    let tag = Tag::new([0; 16]);
+    assert_eq!(
+        cipher.decrypt_in_place_detached(Some(&nonce), b"header", &mut buffer, &tag),
+        Err(Error::InvalidTag)
+    );
+    assert_eq!(buffer, &sealed[..12]);
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "rand")]
+
+use purecrypt::chacha::djb::DjbChaCha20Core;
+use purecrypt::chacha::ietf::ChaCha20Core;
+use purecrypt::chacha::{ChaCha20Rng, DjbChaCha20Rng, Results, Seed};
+use rand_core::block::{BlockRng, BlockRngCore};
+use rand_core::{RngCore, SeedableRng};
+
+const SEED: Seed = Seed::new([5; 32]);
+
+#[test]
+fn generate_fills_consecutive_blocks() {
+    let mut core = ChaCha20Core::from_seed(SEED);
+    let mut results = Results::default();
+    core.generate(&mut results);
+
+    let mut rng = ChaCha20Rng::from_seed(&SEED);
+    for &word in results.as_ref() {
+        assert_eq!(word, rng.next_u32());
+    }
+
+    assert_eq!(results.as_ref().len(), 64);
+    assert_eq!(core.get_counter(), 4);
+}
+
+#[test]
+fn block_rng_matches_ours() {
+    let mut theirs = BlockRng::new(DjbChaCha20Core::from_seed(SEED));
+    let mut ours = DjbChaCha20Rng::from_seed(&SEED);
+
+    for len in [0, 1, 4, 7, 64, 255, 256, 1000] {
+        let (mut a, mut b) = (vec![0; len], vec![0; len]);
+        RngCore::fill_bytes(&mut theirs, &mut a);
+        RngCore::fill_bytes(&mut ours, &mut b);
+        assert_eq!(a, b);
+
+        assert_eq!(theirs.next_u32(), RngCore::next_u32(&mut ours));
+        assert_eq!(theirs.next_u64(), RngCore::next_u64(&mut ours));
+    }
+}
+
+#[test]
+fn block_rng_over_djb_core_matches_rand_chacha() {
+    let mut ours = BlockRng::<DjbChaCha20Core>::from_seed(SEED);
+    let mut theirs = rand_chacha::ChaCha20Rng::from_seed(*SEED.bytes());
+
+    for _ in 0..1000 {
+        assert_eq!(ours.next_u64(), theirs.next_u64());
+    }
+}
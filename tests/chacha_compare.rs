@@ -1,6 +1,6 @@
 use chacha20::cipher::{KeyIvInit, StreamCipher, StreamCipherSeek};
 use purecrypt::chacha::ietf::Nonce as IetfNonce;
-use purecrypt::chacha::{ChaCha20, Key};
+use purecrypt::chacha::{ChaCha20, Key, XChaCha12, XChaCha20, XNonce};
 
 fn ref_chacha20_bytes(key: [u8; 32], nonce: [u8; 12], counter: u32, len: usize) -> Vec<u8> {
     let mut cipher = chacha20::ChaCha20::new(&key.into(), &nonce.into());
@@ -45,3 +45,25 @@ fn ietf_matches_rustcrypto_unaligned_offset() {
 
     assert_eq!(ours[7..], reference);
 }
+
+#[test]
+fn xchacha_matches_rustcrypto() {
+    let key = [0x5au8; 32];
+    let nonce: [u8; 24] = std::array::from_fn(|i| i as u8);
+
+    for len in [0, 1, 63, 64, 65, 300] {
+        let mut ours = vec![0u8; len];
+        XChaCha20::new(&Key::new(key), &XNonce::new(nonce)).apply_keystream(&mut ours);
+
+        let mut reference = vec![0u8; len];
+        chacha20::XChaCha20::new(&key.into(), &nonce.into()).apply_keystream(&mut reference);
+        assert_eq!(ours, reference);
+
+        let mut ours = vec![0u8; len];
+        XChaCha12::new(&Key::new(key), &XNonce::new(nonce)).apply_keystream(&mut ours);
+
+        let mut reference = vec![0u8; len];
+        chacha20::XChaCha12::new(&key.into(), &nonce.into()).apply_keystream(&mut reference);
+        assert_eq!(ours, reference);
+    }
+}
@@ -1,6 +1,7 @@
 use hex_literal::hex;
 use purecrypt::chacha::Key;
 use purecrypt::chacha::djb::{DjbChaCha8, DjbChaCha12, DjbChaCha20, Nonce as DjbNonce};
+use purecrypt::chacha::xchacha::hchacha;
 
 #[test]
 fn djb_chacha_tc1_vectors_draft_strombergson() {
@@ -57,3 +58,23 @@ fn djb_chacha_tc1_vectors_draft_strombergson() {
     cipher_20.apply_keystream(&mut out_20);
     assert_eq!(out_20.as_slice(), expected_20.as_slice());
 }
+
+#[test]
+fn hchacha20_draft_xchacha() {
+    // draft-irtf-cfrg-xchacha-03 section 2.2.1:
+    // https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-xchacha-03
+    let key = Key::new(hex!(
+        "000102030405060708090a0b0c0d0e0f"
+        "101112131415161718191a1b1c1d1e1f"
+    ));
+    let input = hex!("000000090000004a0000000031415927");
+
+    let subkey = hchacha::<20>(&key, &input);
+    assert_eq!(
+        subkey.bytes(),
+        &hex!(
+            "82413b4227b27bfed30e42508a877d73"
+            "a0f9e4d58a74a853c12ec41326d3ecdc"
+        )
+    );
+}
@@ -0,0 +1,61 @@
+use purecrypt::chacha::ietf::Nonce;
+use purecrypt::chacha::{ChaCha20, FastKeyErasureChaCha20Rng, Key, Seed};
+
+const BATCH_LEN: usize = 768;
+
+fn batch(key: &[u8; 32]) -> Vec<u8> {
+    let mut out = vec![0; BATCH_LEN];
+    ChaCha20::new(&Key::new(*key), &Nonce::default()).apply_keystream(&mut out);
+
+    out
+}
+
+#[test]
+fn batches_chain_through_their_first_32_bytes() {
+    let seed = [0x5a; 32];
+
+    let mut expected = Vec::new();
+    let mut key = seed;
+    for _ in 0..3 {
+        let batch = batch(&key);
+        key = batch[..32].try_into().unwrap();
+        expected.extend_from_slice(&batch[32..]);
+    }
+
+    let mut rng = FastKeyErasureChaCha20Rng::from_seed(&Seed::new(seed));
+    let mut out = vec![0; expected.len()];
+    rng.fill_bytes(&mut out);
+
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn draw_sizes_do_not_change_the_stream() {
+    let seed = Seed::new([3; 32]);
+
+    let mut whole = vec![0; 5000];
+    FastKeyErasureChaCha20Rng::from_seed(&seed).fill_bytes(&mut whole);
+
+    let mut rng = FastKeyErasureChaCha20Rng::from_seed(&seed);
+    let mut pieces = Vec::new();
+    let mut len = 0;
+    while pieces.len() < 4000 {
+        len += 1;
+        let mut piece = vec![0; len % 97];
+        rng.fill_bytes(&mut piece);
+        pieces.extend_from_slice(&piece);
+    }
+
+    assert_eq!(pieces, whole[..pieces.len()]);
+
+    let next = u64::from_le_bytes(whole[pieces.len()..pieces.len() + 8].try_into().unwrap());
+    assert_eq!(rng.next_u64(), next);
+}
+
+#[test]
+fn seeded_from_the_os() {
+    let mut a = FastKeyErasureChaCha20Rng::from_entropy().unwrap();
+    let mut b = FastKeyErasureChaCha20Rng::from_entropy().unwrap();
+
+    assert_ne!(a.next_u64(), b.next_u64());
+}
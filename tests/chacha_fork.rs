@@ -0,0 +1,85 @@
+use purecrypt::chacha::ietf::StreamId;
+use purecrypt::chacha::{ChaCha20Rng, Constants, Seed};
+
+const SEED: Seed = Seed::new([77; 32]);
+
+fn head(rng: &mut ChaCha20Rng) -> [u8; 64] {
+    let mut out = [0; 64];
+    rng.fill_bytes(&mut out);
+
+    out
+}
+
+#[test]
+fn forks_are_reproducible() {
+    let parent = ChaCha20Rng::from_seed(&SEED);
+
+    let mut a = parent.fork(b"worker");
+    let mut b = ChaCha20Rng::from_seed(&SEED).fork(b"worker");
+
+    assert_eq!(a.get_stream_id(), b.get_stream_id());
+    assert_eq!(head(&mut a), head(&mut b));
+    assert_eq!(a.get_seed(), parent.get_seed());
+}
+
+#[test]
+fn fork_ignores_the_parent_position() {
+    let fresh = ChaCha20Rng::from_seed(&SEED);
+    let mut used = ChaCha20Rng::from_seed(&SEED);
+    head(&mut used);
+
+    assert_eq!(
+        head(&mut fresh.fork(b"label")),
+        head(&mut used.fork(b"label"))
+    );
+}
+
+#[test]
+fn labels_and_parents_separate_streams() {
+    let parent = ChaCha20Rng::from_seed(&SEED);
+    let other = ChaCha20Rng::new(&SEED, &StreamId::new([1; 12]));
+
+    let mut heads = vec![
+        head(&mut parent.clone()),
+        head(&mut parent.fork(b"a")),
+        head(&mut parent.fork(b"b")),
+        head(&mut parent.fork(b"")),
+        head(&mut parent.fork(b"a").fork(b"a")),
+        head(&mut other.fork(b"a")),
+    ];
+
+    heads.sort();
+    heads.dedup();
+    assert_eq!(heads.len(), 6);
+}
+
+#[test]
+fn split_matches_indexed_forks() {
+    let parent = ChaCha20Rng::from_seed(&SEED);
+    let mut children = parent.split(8);
+
+    assert_eq!(children.len(), 8);
+
+    for (i, child) in children.iter_mut().enumerate() {
+        let mut fork = parent.fork(&(i as u64).to_le_bytes());
+
+        assert_eq!(child.get_counter(), 0);
+        assert_eq!(head(child), head(&mut fork));
+    }
+
+    let mut ids: Vec<_> = children
+        .iter()
+        .map(|c| *c.get_stream_id().bytes())
+        .collect();
+    ids.sort();
+    ids.dedup();
+    assert_eq!(ids.len(), 8);
+}
+
+#[test]
+fn forks_keep_custom_constants() {
+    let constants = Constants::new(*b"custom constants");
+    let parent = ChaCha20Rng::from_seed(&SEED).with_constants(&constants);
+
+    assert_eq!(parent.fork(b"x").get_constants(), &constants);
+}
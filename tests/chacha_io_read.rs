@@ -0,0 +1,51 @@
+use std::io::{self, Read};
+
+use purecrypt::chacha::{ChaCha20Rng, DjbChaCha20Rng, Seed};
+
+#[test]
+fn read_matches_fill_bytes() {
+    let seed = Seed::from_label("io read");
+
+    let mut expected = [0_u8; 200];
+    ChaCha20Rng::from_seed(&seed).fill_bytes(&mut expected);
+
+    let mut rng = ChaCha20Rng::from_seed(&seed);
+    let mut buf = [0_u8; 200];
+    assert_eq!(rng.read(&mut buf).unwrap(), buf.len());
+    assert_eq!(buf, expected);
+
+    let mut expected = [0_u8; 200];
+    DjbChaCha20Rng::from_seed(&seed).fill_bytes(&mut expected);
+
+    let mut rng = DjbChaCha20Rng::from_seed(&seed);
+    let mut buf = [0_u8; 200];
+    rng.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn copy_through_take() {
+    let seed = Seed::from_label("io copy");
+
+    let mut out = Vec::new();
+    let copied = io::copy(&mut ChaCha20Rng::from_seed(&seed).take(10_000), &mut out).unwrap();
+    assert_eq!(copied, 10_000);
+
+    let mut expected = vec![0_u8; 10_000];
+    ChaCha20Rng::from_seed(&seed).fill_bytes(&mut expected);
+    assert_eq!(out, expected);
+
+    let mut out = Vec::new();
+    DjbChaCha20Rng::from_seed(&seed)
+        .take(1000)
+        .read_to_end(&mut out)
+        .unwrap();
+    assert_eq!(out.len(), 1000);
+}
+
+#[test]
+fn empty_read() {
+    let mut rng = ChaCha20Rng::from_seed(&Seed::from_u64(7));
+    assert_eq!(rng.read(&mut []).unwrap(), 0);
+    assert_eq!(rng.get_word_pos(), 0);
+}
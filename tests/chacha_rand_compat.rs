@@ -0,0 +1,106 @@
+#![cfg(feature = "rand")]
+
+use purecrypt::chacha::{DjbChaCha20Rng, DjbChaChaRng, Seed};
+use rand_core::{RngCore, SeedableRng};
+
+// drives the same random mix of calls through both generators
+fn differential<const ROUNDS: usize, R: RngCore + SeedableRng<Seed = [u8; 32]>>(
+    set_word_pos: fn(&mut R, u128),
+    get_word_pos: fn(&R) -> u128,
+    set_stream: fn(&mut R, u64),
+) {
+    // a fixed xorshift so failures reproduce
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let seed: [u8; 32] = std::array::from_fn(|i| i as u8 * 3);
+    let mut ours = DjbChaChaRng::<ROUNDS>::from_seed(&Seed::new(seed));
+    let mut theirs = R::from_seed(seed);
+
+    for _ in 0..5000 {
+        match next() % 8 {
+            0 | 1 => assert_eq!(ours.next_u32(), theirs.next_u32()),
+            2 | 3 => assert_eq!(ours.next_u64(), theirs.next_u64()),
+            4 | 5 => {
+                let len = (next() % 300) as usize;
+                let (mut a, mut b) = (vec![0; len], vec![0; len]);
+                RngCore::fill_bytes(&mut ours, &mut a);
+                theirs.fill_bytes(&mut b);
+                assert_eq!(a, b);
+            }
+            6 => {
+                // near the start or close to the counter wrapping
+                let pos = match next() % 2 {
+                    0 => u128::from(next() % 4096),
+                    _ => (1 << 68) - u128::from(next() % 4096) - 1,
+                };
+                ours.set_word_pos(pos);
+                set_word_pos(&mut theirs, pos);
+            }
+            _ => {
+                let stream = next() % 4;
+                ours.set_stream_id(stream);
+                set_stream(&mut theirs, stream);
+            }
+        }
+
+        assert_eq!(ours.get_word_pos(), get_word_pos(&theirs));
+    }
+}
+
+#[test]
+fn djb_chacha20_matches_rand_chacha() {
+    differential::<20, rand_chacha::ChaCha20Rng>(
+        rand_chacha::ChaCha20Rng::set_word_pos,
+        rand_chacha::ChaCha20Rng::get_word_pos,
+        rand_chacha::ChaCha20Rng::set_stream,
+    );
+}
+
+#[test]
+fn djb_chacha12_matches_rand_chacha() {
+    differential::<12, rand_chacha::ChaCha12Rng>(
+        rand_chacha::ChaCha12Rng::set_word_pos,
+        rand_chacha::ChaCha12Rng::get_word_pos,
+        rand_chacha::ChaCha12Rng::set_stream,
+    );
+}
+
+#[test]
+fn djb_chacha8_matches_rand_chacha() {
+    differential::<8, rand_chacha::ChaCha8Rng>(
+        rand_chacha::ChaCha8Rng::set_word_pos,
+        rand_chacha::ChaCha8Rng::get_word_pos,
+        rand_chacha::ChaCha8Rng::set_stream,
+    );
+}
+
+#[test]
+fn seed_from_u64_matches() {
+    // rand_core's default seeding is shared through SeedableRng
+    let mut ours = DjbChaCha20Rng::seed_from_u64(7);
+    let mut theirs = rand_chacha::ChaCha20Rng::seed_from_u64(7);
+
+    for _ in 0..100 {
+        assert_eq!(ours.next_u64(), theirs.next_u64());
+    }
+}
+
+#[test]
+fn unaligned_fills_skip_the_rest_of_the_word() {
+    let mut ours = DjbChaCha20Rng::from_seed(&Seed::default());
+    let mut theirs = rand_chacha::ChaCha20Rng::from_seed([0; 32]);
+
+    let (mut a, mut b) = ([0; 3], [0; 3]);
+    RngCore::fill_bytes(&mut ours, &mut a);
+    theirs.fill_bytes(&mut b);
+    assert_eq!(a, b);
+
+    assert_eq!(ours.get_word_pos(), 1);
+    assert_eq!(ours.next_u32(), theirs.next_u32());
+}
@@ -0,0 +1,230 @@
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU32, AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use purecrypt::chacha::{ChaCha20Rng, ReseedingChaCha20Rng, Seed};
+use purecrypt::os;
+
+// each test owns its statics, the harness runs tests on parallel threads
+macro_rules! counting_source {
+    ($calls:ident, $source:ident) => {
+        static $calls: AtomicUsize = AtomicUsize::new(0);
+
+        fn $source(dst: &mut [u8]) -> Result<(), os::Error> {
+            $calls.fetch_add(1, Ordering::SeqCst);
+            dst.fill(0xab);
+
+            Ok(())
+        }
+    };
+}
+
+fn draw(rng: &mut ReseedingChaCha20Rng, len: usize) -> Vec<u8> {
+    let mut out = vec![0; len];
+    rng.fill_bytes(&mut out);
+
+    out
+}
+
+#[test]
+fn starts_from_the_entropy_source() {
+    counting_source!(CALLS, source);
+
+    let mut rng = ReseedingChaCha20Rng::from_entropy_source(source).unwrap();
+    let mut expected = ChaCha20Rng::from_seed(&Seed::new([0xab; 32]));
+
+    let mut want = [0; 100];
+    expected.fill_bytes(&mut want);
+    assert_eq!(draw(&mut rng, 100), want);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn reseeds_after_the_byte_budget() {
+    counting_source!(CALLS, source);
+
+    let mut rng = ReseedingChaCha20Rng::from_entropy_source(source)
+        .unwrap()
+        .with_reseed_bytes(64);
+
+    draw(&mut rng, 60);
+    rng.next_u32();
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+
+    rng.next_u32();
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+
+    // draws larger than the budget reseed every time
+    draw(&mut rng, 65);
+    draw(&mut rng, 65);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn reseed_mixes_in_the_old_state() {
+    counting_source!(CALLS, source);
+
+    let mut rng = ReseedingChaCha20Rng::from_entropy_source(source).unwrap();
+    let mut shadow = ChaCha20Rng::from_seed(&Seed::new([0xab; 32]));
+
+    assert_eq!(draw(&mut rng, 10), {
+        let mut want = [0; 10];
+        shadow.fill_bytes(&mut want);
+        want
+    });
+
+    rng.reseed().unwrap();
+
+    // the 32 bytes right after the ones drawn
+    let mut seed = [0; 32];
+    shadow.fill_bytes(&mut seed);
+    seed.iter_mut().for_each(|b| *b ^= 0xab);
+
+    let mut want = [0; 64];
+    ChaCha20Rng::from_seed(&Seed::new(seed)).fill_bytes(&mut want);
+    assert_eq!(draw(&mut rng, 64), want);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn pid_change_forces_a_reseed() {
+    counting_source!(CALLS, source);
+    static PID: AtomicU32 = AtomicU32::new(100);
+
+    fn pid() -> u32 {
+        PID.load(Ordering::SeqCst)
+    }
+
+    let mut parent = ReseedingChaCha20Rng::from_entropy_source(source)
+        .unwrap()
+        .with_pid_source(pid);
+    draw(&mut parent, 10);
+
+    // the child continues from a copy of the parent's state, modelled by a
+    // second generator that took the same steps
+    let mut child = ReseedingChaCha20Rng::from_entropy_source(source)
+        .unwrap()
+        .with_pid_source(pid);
+    draw(&mut child, 10);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+
+    // the rest of the current block is still handed out
+    PID.store(101, Ordering::SeqCst);
+    draw(&mut child, 32);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+
+    let child_out = draw(&mut child, 32);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 3);
+
+    // the parent reseeds too since the fake pid is shared, but a generator
+    // that ignored the change would have produced the plain stream
+    let mut unforked = ChaCha20Rng::from_seed(&Seed::new([0xab; 32]));
+    let mut stale = [0; 74];
+    unforked.fill_bytes(&mut stale);
+    assert_ne!(child_out, stale[42..]);
+
+    // no further reseeds while the pid stays the same
+    draw(&mut child, 64);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn pid_is_read_once_per_block() {
+    counting_source!(CALLS, source);
+    static PID_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    fn pid() -> u32 {
+        PID_CALLS.fetch_add(1, Ordering::SeqCst);
+
+        100
+    }
+
+    let mut rng = ReseedingChaCha20Rng::from_entropy_source(source)
+        .unwrap()
+        .with_pid_source(pid);
+    assert_eq!(PID_CALLS.load(Ordering::SeqCst), 1);
+
+    (0..16).for_each(|_| {
+        rng.next_u32();
+    });
+    assert_eq!(PID_CALLS.load(Ordering::SeqCst), 2);
+
+    rng.next_u64();
+    draw(&mut rng, 56);
+    assert_eq!(PID_CALLS.load(Ordering::SeqCst), 3);
+
+    // a draw spanning blocks still checks once
+    draw(&mut rng, 200);
+    assert_eq!(PID_CALLS.load(Ordering::SeqCst), 4);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn reseeds_after_the_interval() {
+    counting_source!(CALLS, source);
+
+    static BASE: OnceLock<Instant> = OnceLock::new();
+    static ELAPSED_MS: AtomicU64 = AtomicU64::new(0);
+
+    fn clock() -> Instant {
+        *BASE.get_or_init(Instant::now) + Duration::from_millis(ELAPSED_MS.load(Ordering::SeqCst))
+    }
+
+    let mut rng = ReseedingChaCha20Rng::from_entropy_source(source)
+        .unwrap()
+        .with_clock_source(clock)
+        .with_reseed_interval(Some(Duration::from_millis(20)));
+
+    rng.next_u64();
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+
+    ELAPSED_MS.store(19, Ordering::SeqCst);
+    rng.next_u64();
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+
+    ELAPSED_MS.store(20, Ordering::SeqCst);
+    rng.next_u64();
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+
+    // measured from the last reseed
+    ELAPSED_MS.store(39, Ordering::SeqCst);
+    rng.next_u64();
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+
+    ELAPSED_MS.store(40, Ordering::SeqCst);
+    rng.next_u64();
+    assert_eq!(CALLS.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn entropy_failures_propagate() {
+    static FAIL: AtomicU32 = AtomicU32::new(0);
+
+    fn flaky(dst: &mut [u8]) -> Result<(), os::Error> {
+        match FAIL.load(Ordering::SeqCst) {
+            0 => {
+                dst.fill(1);
+                Ok(())
+            }
+            code => Err(os::Error::Os(code as i32)),
+        }
+    }
+
+    let mut rng = ReseedingChaCha20Rng::from_entropy_source(flaky)
+        .unwrap()
+        .with_reseed_bytes(8);
+
+    FAIL.store(5, Ordering::SeqCst);
+    assert_eq!(rng.try_fill_bytes(&mut [0; 8]), Ok(()));
+    assert_eq!(rng.try_fill_bytes(&mut [0; 8]), Err(os::Error::Os(5)));
+    assert_eq!(rng.reseed(), Err(os::Error::Os(5)));
+    assert!(ReseedingChaCha20Rng::from_entropy_source(flaky).is_err());
+}
+
+#[test]
+fn os_seeded_generators_differ() {
+    let mut a = ReseedingChaCha20Rng::new().unwrap();
+    let mut b = ReseedingChaCha20Rng::new().unwrap();
+
+    assert_ne!(draw(&mut a, 32), draw(&mut b, 32));
+}
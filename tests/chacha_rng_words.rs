@@ -0,0 +1,89 @@
+use purecrypt::chacha::{ChaCha20Rng, DjbChaCha20Rng, Seed};
+
+const SEED: Seed = Seed::new([9; 32]);
+
+fn keystream_words(len: usize) -> Vec<u32> {
+    let mut bytes = vec![0; len * 4];
+    ChaCha20Rng::from_seed(&SEED).fill_bytes(&mut bytes);
+
+    bytes
+        .chunks_exact(4)
+        .map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+        .collect()
+}
+
+#[test]
+fn next_u32_walks_the_keystream() {
+    let words = keystream_words(100);
+    let mut rng = ChaCha20Rng::from_seed(&SEED);
+
+    for &word in &words {
+        assert_eq!(rng.next_u32(), word);
+    }
+}
+
+#[test]
+fn next_u64_straddles_blocks() {
+    let words = keystream_words(100);
+
+    // an odd offset puts one u64 across the end of the first block
+    let mut rng = ChaCha20Rng::from_seed(&SEED);
+    assert_eq!(rng.next_u32(), words[0]);
+
+    for pair in words[1..99].chunks_exact(2) {
+        let expected = u64::from(pair[0]) | (u64::from(pair[1]) << 32);
+        assert_eq!(rng.next_u64(), expected);
+    }
+}
+
+#[test]
+fn mixed_draws_are_byte_exact() {
+    let words = keystream_words(100);
+    let stream: Vec<u8> = words.iter().flat_map(|w| w.to_le_bytes()).collect();
+    let mut rng = ChaCha20Rng::from_seed(&SEED);
+
+    let mut three = [0; 3];
+    rng.fill_bytes(&mut three);
+    assert_eq!(three, stream[..3]);
+
+    // draws carry on from the byte after the last one taken
+    assert_eq!(rng.next_u32().to_le_bytes(), stream[3..7]);
+    assert_eq!(rng.next_u64().to_le_bytes(), stream[7..15]);
+    assert_eq!(rng.get_word_pos(), 4);
+
+    let mut long = [0; 150];
+    rng.fill_bytes(&mut long);
+    assert_eq!(long, stream[15..165]);
+    assert_eq!(rng.next_u32().to_le_bytes(), stream[165..169]);
+}
+
+#[test]
+fn any_split_gives_the_same_stream() {
+    let mut expected = [0; 1000];
+    ChaCha20Rng::from_seed(&SEED).fill_bytes(&mut expected);
+
+    let mut rng = ChaCha20Rng::from_seed(&SEED);
+    let mut out = Vec::new();
+    let mut len = 1;
+    while out.len() < expected.len() {
+        let mut chunk = vec![0; len.min(expected.len() - out.len())];
+        rng.fill_bytes(&mut chunk);
+        out.extend_from_slice(&chunk);
+        len += 1;
+    }
+
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn djb_next_u32_matches_fill_bytes() {
+    let mut a = DjbChaCha20Rng::from_seed(&SEED);
+    let mut b = DjbChaCha20Rng::from_seed(&SEED);
+
+    let mut bytes = [0; 4 * 70];
+    a.fill_bytes(&mut bytes);
+
+    for chunk in bytes.chunks_exact(8) {
+        assert_eq!(b.next_u64(), u64::from_le_bytes(chunk.try_into().unwrap()));
+    }
+}
@@ -0,0 +1,116 @@
+use purecrypt::chacha::{ChaCha20Rng, DjbChaCha20Rng, Seed};
+
+const SEED: Seed = Seed::new([1; 32]);
+
+#[test]
+fn ranges_stay_in_bounds() {
+    let mut rng = ChaCha20Rng::from_seed(&SEED);
+
+    for _ in 0..10_000 {
+        assert!((10..20).contains(&rng.gen_range(10_u32..20)));
+        assert!((-5..=5).contains(&rng.gen_range(-5_i64..=5)));
+        assert_eq!(rng.gen_range(7_u8..8), 7);
+        assert_eq!(rng.gen_range(i16::MIN..=i16::MIN), i16::MIN);
+        assert!(rng.gen_range(0_usize..3) < 3);
+    }
+
+    // whole types, including the 64-bit ones that skip rejection
+    let mut seen_negative = false;
+    for _ in 0..1000 {
+        seen_negative |= rng.gen_range(i8::MIN..=i8::MAX) < 0;
+        rng.gen_range(u64::MIN..=u64::MAX);
+        rng.gen_range(i64::MIN..=i64::MAX);
+    }
+    assert!(seen_negative);
+}
+
+#[test]
+#[should_panic(expected = "empty range")]
+fn empty_range_panics() {
+    ChaCha20Rng::from_seed(&SEED).gen_range(5_u32..5);
+}
+
+#[test]
+#[should_panic(expected = "empty range")]
+fn inverted_inclusive_range_panics() {
+    #[allow(clippy::reversed_empty_ranges)]
+    DjbChaCha20Rng::from_seed(&SEED).gen_range(3_i32..=2);
+}
+
+#[test]
+fn small_ranges_are_uniform() {
+    let mut rng = DjbChaCha20Rng::from_seed(&SEED);
+    let mut counts = [0_u32; 6];
+
+    for _ in 0..60_000 {
+        counts[rng.gen_range(0_usize..6)] += 1;
+    }
+
+    // about 10 standard deviations of slack
+    for count in counts {
+        assert!((9_000..11_000).contains(&count), "{counts:?}");
+    }
+}
+
+#[test]
+fn floats_are_in_the_unit_interval() {
+    let mut rng = ChaCha20Rng::from_seed(&SEED);
+    let (mut sum32, mut sum64) = (0.0, 0.0);
+
+    for _ in 0..10_000 {
+        let (x, y) = (rng.next_f32(), rng.next_f64());
+        assert!((0.0..1.0).contains(&x));
+        assert!((0.0..1.0).contains(&y));
+
+        sum32 += f64::from(x);
+        sum64 += y;
+    }
+
+    assert!((sum32 / 10_000.0 - 0.5).abs() < 0.02);
+    assert!((sum64 / 10_000.0 - 0.5).abs() < 0.02);
+}
+
+#[test]
+fn shuffle_permutes_reproducibly() {
+    let mut a: Vec<u32> = (0..100).collect();
+    let mut b = a.clone();
+
+    ChaCha20Rng::from_seed(&SEED).shuffle(&mut a);
+    ChaCha20Rng::from_seed(&SEED).shuffle(&mut b);
+    assert_eq!(a, b);
+    assert_ne!(a, (0..100).collect::<Vec<_>>());
+
+    a.sort();
+    assert_eq!(a, (0..100).collect::<Vec<_>>());
+
+    ChaCha20Rng::from_seed(&SEED).shuffle::<u8>(&mut []);
+}
+
+#[test]
+fn shuffle_reaches_every_permutation() {
+    let mut rng = DjbChaCha20Rng::from_seed(&SEED);
+    let mut seen = std::collections::HashSet::new();
+
+    for _ in 0..1000 {
+        let mut items = [0, 1, 2, 3];
+        rng.shuffle(&mut items);
+        seen.insert(items);
+    }
+
+    assert_eq!(seen.len(), 24);
+}
+
+#[test]
+fn choose_picks_members() {
+    let mut rng = ChaCha20Rng::from_seed(&SEED);
+    let items = ["a", "b", "c"];
+
+    assert_eq!(rng.choose::<u8>(&[]), None);
+
+    let mut seen = [false; 3];
+    for _ in 0..100 {
+        let item = rng.choose(&items).unwrap();
+        seen[items.iter().position(|x| x == item).unwrap()] = true;
+    }
+    assert_eq!(seen, [true; 3]);
+}
@@ -0,0 +1,50 @@
+use purecrypt::chacha::Seed;
+use purecrypt::sha2::Sha256;
+
+#[test]
+fn from_u64_known_answer() {
+    // rand_core's PCG32 expansion of 0, first words 0xf973f2ec and 0x45cdb581
+    let seed = Seed::from_u64(0);
+    assert_eq!(
+        &seed.bytes()[..8],
+        &[0xec, 0xf2, 0x73, 0xf9, 0x81, 0xb5, 0xcd, 0x45]
+    );
+
+    assert_ne!(Seed::from_u64(1), Seed::from_u64(2));
+    assert_eq!(Seed::from_u64(u64::MAX), Seed::from_u64(u64::MAX));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn from_u64_matches_rand_core() {
+    use purecrypt::chacha::{ChaCha20Rng, DjbChaCha20Rng};
+    use rand_core::{RngCore, SeedableRng};
+
+    for state in [0, 1, 42, 0xdead_beef, u64::MAX] {
+        let mut ours = ChaCha20Rng::from_seed(&Seed::from_u64(state));
+        let mut theirs = <ChaCha20Rng as SeedableRng>::seed_from_u64(state);
+        assert_eq!(ours.next_u64(), RngCore::next_u64(&mut theirs));
+
+        // and through rand_chacha, which seeds the same way
+        let mut ours = DjbChaCha20Rng::from_seed(&Seed::from_u64(state));
+        let mut reference = rand_chacha::ChaCha20Rng::seed_from_u64(state);
+        assert_eq!(ours.next_u64(), reference.next_u64());
+    }
+}
+
+#[test]
+fn from_label_is_a_domain_separated_hash() {
+    let seed = Seed::from_label("monte carlo run 7");
+
+    assert_eq!(seed, Seed::from_label("monte carlo run 7"));
+    assert_ne!(seed, Seed::from_label("monte carlo run 8"));
+    assert_ne!(seed, Seed::from_label(""));
+
+    let mut hasher = Sha256::new();
+    hasher.update(b"purecrypt chacha seed label");
+    hasher.update(b"monte carlo run 7");
+    assert_eq!(seed.bytes(), &hasher.finalize());
+
+    // not the bare hash of the label
+    assert_ne!(seed.bytes(), &Sha256::digest(b"monte carlo run 7"));
+}
@@ -0,0 +1,54 @@
+use purecrypt::chacha::{ThreadRng, thread_rng};
+
+#[test]
+fn handles_share_the_thread_state() {
+    let mut a = thread_rng();
+    let mut b = a.clone();
+    let mut c = ThreadRng::default();
+
+    let draws = [a.next_u64(), b.next_u64(), c.next_u64(), a.next_u64()];
+    for (i, x) in draws.iter().enumerate() {
+        assert!(draws[i + 1..].iter().all(|y| x != y));
+    }
+}
+
+#[test]
+fn threads_get_their_own_generators() {
+    let mut ours = [0; 32];
+    thread_rng().fill_bytes(&mut ours);
+
+    let theirs = std::thread::spawn(|| {
+        let mut out = [0; 32];
+        thread_rng().fill_bytes(&mut out);
+        out
+    })
+    .join()
+    .unwrap();
+
+    assert_ne!(ours, theirs);
+}
+
+#[test]
+fn survives_many_reseeds() {
+    let mut rng = thread_rng();
+    let mut buf = vec![0; 1 << 20];
+
+    // far past the reseed budget
+    for _ in 0..4 {
+        rng.fill_bytes(&mut buf);
+    }
+
+    assert!(buf.iter().any(|&b| b != 0));
+    assert_eq!(format!("{rng:?}"), "ThreadRng { .. }");
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn usable_as_a_crypto_rng() {
+    use purecrypt::chacha::Key;
+
+    let a = Key::random_from_rng(&mut thread_rng());
+    let b = Key::random_from_rng(&mut thread_rng());
+
+    assert_ne!(a, b);
+}
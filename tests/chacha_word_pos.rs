@@ -0,0 +1,95 @@
+use purecrypt::chacha::{ChaCha20Rng, DjbChaCha20Rng, Seed};
+
+const SEED: Seed = Seed::new([42; 32]);
+
+fn bytes<const N: usize>(mut fill: impl FnMut(&mut [u8])) -> [u8; N] {
+    let mut out = [0; N];
+    fill(&mut out);
+
+    out
+}
+
+#[test]
+fn word_pos_counts_consumed_words() {
+    let mut ietf = ChaCha20Rng::from_seed(&SEED);
+    let mut djb = DjbChaCha20Rng::from_seed(&SEED);
+    assert_eq!(ietf.get_word_pos(), 0);
+    assert_eq!(djb.get_word_pos(), 0);
+
+    let mut consumed = 0;
+    for len in [4, 60, 64, 8, 200, 132, 4] {
+        let mut buf = vec![0; len];
+        ietf.fill_bytes(&mut buf);
+        djb.fill_bytes(&mut buf);
+        consumed += len as u128 / 4;
+
+        assert_eq!(ietf.get_word_pos(), consumed);
+        assert_eq!(djb.get_word_pos(), consumed);
+    }
+}
+
+#[test]
+fn partly_used_word_counts_as_used() {
+    let mut rng = DjbChaCha20Rng::from_seed(&SEED);
+    rng.fill_bytes(&mut [0; 5]);
+
+    assert_eq!(rng.get_word_pos(), 2);
+}
+
+#[test]
+fn set_word_pos_resumes_exactly() {
+    for pos in [0, 1, 15, 16, 17, 1000, 1 << 33] {
+        let mut reference = ChaCha20Rng::from_seed(&SEED);
+        reference.set_counter((pos / 16) as u32);
+        reference.fill_bytes(&mut vec![0; pos as usize % 16 * 4]);
+
+        let mut resumed = ChaCha20Rng::from_seed(&SEED).with_word_pos(pos);
+        assert_eq!(resumed.get_word_pos(), pos);
+        assert_eq!(
+            bytes::<100>(|b| resumed.fill_bytes(b)),
+            bytes::<100>(|b| reference.fill_bytes(b)),
+        );
+
+        let mut reference = DjbChaCha20Rng::from_seed(&SEED);
+        reference.set_counter((pos / 16) as u64);
+        reference.fill_bytes(&mut vec![0; pos as usize % 16 * 4]);
+
+        let mut resumed = DjbChaCha20Rng::from_seed(&SEED).with_word_pos(pos);
+        assert_eq!(resumed.get_word_pos(), pos);
+        assert_eq!(
+            bytes::<100>(|b| resumed.fill_bytes(b)),
+            bytes::<100>(|b| reference.fill_bytes(b)),
+        );
+    }
+}
+
+#[test]
+fn checkpoint_and_restore() {
+    let mut rng = DjbChaCha20Rng::from_seed(&SEED);
+    rng.fill_bytes(&mut [0; 1236]);
+
+    let checkpoint = rng.get_word_pos();
+    let expected = bytes::<300>(|b| rng.fill_bytes(b));
+
+    rng.set_word_pos(checkpoint);
+    assert_eq!(bytes::<300>(|b| rng.fill_bytes(b)), expected);
+}
+
+#[test]
+fn word_pos_wraps_with_the_counter() {
+    // the buffered block belongs to the last counter value before the wrap
+    let mut ietf = ChaCha20Rng::from_seed(&SEED).with_word_pos((1 << 36) - 1);
+    assert_eq!(ietf.get_word_pos(), (1 << 36) - 1);
+    ietf.fill_bytes(&mut [0; 4]);
+    assert_eq!(ietf.get_word_pos(), 0);
+    assert_eq!(ietf.get_counter(), 0);
+
+    let mut djb = DjbChaCha20Rng::from_seed(&SEED).with_word_pos((1 << 68) - 1);
+    assert_eq!(djb.get_word_pos(), (1 << 68) - 1);
+    djb.fill_bytes(&mut [0; 4]);
+    assert_eq!(djb.get_word_pos(), 0);
+
+    // positions past the counter are taken modulo its range
+    let ietf = ChaCha20Rng::from_seed(&SEED).with_word_pos((1 << 36) + 17);
+    assert_eq!(ietf.get_word_pos(), 17);
+}
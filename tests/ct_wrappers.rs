@@ -0,0 +1,113 @@
+use purecrypt::aead::Tag;
+use purecrypt::aes::Key128;
+use purecrypt::chacha::ietf::{Nonce, StreamId};
+use purecrypt::chacha::{Constants, Key, Seed, XNonce};
+use purecrypt::ct::{ConditionallySelectable, ConstantTimeEq};
+use purecrypt::ristretto255::Scalar;
+use purecrypt::x25519::{PublicKey, StaticSecret};
+
+#[test]
+fn ct_eq_primitives() {
+    assert!(7_u8.ct_eq(&7));
+    assert!(!7_u8.ct_eq(&6));
+    assert!(u64::MAX.ct_eq(&u64::MAX));
+    assert!(1_u32.ct_ne(&2));
+
+    assert!([1_u8, 2, 3].ct_eq(&[1, 2, 3]));
+    assert!(![1_u8, 2, 3].ct_eq(&[1, 2, 4]));
+
+    // slices of different lengths are never equal
+    assert!(!b"abc"[..].ct_eq(&b"abcd"[..]));
+    assert!(!b""[..].ct_eq(&b"a"[..]));
+    assert!(b""[..].ct_eq(&b""[..]));
+}
+
+#[test]
+fn conditional_select_primitives() {
+    assert_eq!(u8::conditional_select(&1, &2, false), 1);
+    assert_eq!(u8::conditional_select(&1, &2, true), 2);
+    assert_eq!(u128::conditional_select(&0, &u128::MAX, true), u128::MAX);
+
+    let mut a = [1_u8; 4];
+    a.conditional_assign(&[2; 4], false);
+    assert_eq!(a, [1; 4]);
+    a.conditional_assign(&[2; 4], true);
+    assert_eq!(a, [2; 4]);
+
+    let (mut x, mut y) = (5_u32, 9_u32);
+    u32::conditional_swap(&mut x, &mut y, false);
+    assert_eq!((x, y), (5, 9));
+    u32::conditional_swap(&mut x, &mut y, true);
+    assert_eq!((x, y), (9, 5));
+}
+
+#[test]
+fn wrappers_compare_and_select() {
+    let a = Key::new([1; 32]);
+    let b = Key::new([2; 32]);
+
+    assert_eq!(a, Key::new([1; 32]));
+    assert_ne!(a, b);
+    assert!(a.ct_eq(&a.clone()));
+
+    assert_eq!(Key::conditional_select(&a, &b, true), b);
+    assert_eq!(Key::conditional_select(&a, &b, false), a);
+
+    assert_eq!(Seed::new([3; 32]), Seed::new([3; 32]));
+    assert_ne!(Nonce::new([0; 12]), Nonce::new([1; 12]));
+    assert_eq!(Tag::new([4; 16]), Tag::new([4; 16]));
+    assert_ne!(Key128::new([0; 16]), Key128::new([1; 16]));
+}
+
+#[test]
+fn secrets_are_redacted() {
+    assert_eq!(format!("{:?}", Key::new([0xab; 32])), "Key(<redacted>)");
+    assert_eq!(format!("{:?}", Seed::new([0xab; 32])), "Seed(<redacted>)");
+    assert_eq!(
+        format!("{:?}", Key128::new([0xab; 16])),
+        "Key128(<redacted>)"
+    );
+    assert_eq!(
+        format!("{:?}", StaticSecret::new([0xab; 32])),
+        "StaticSecret(<redacted>)"
+    );
+    assert_eq!(format!("{:?}", Scalar::from_u64(5)), "Scalar(<redacted>)");
+}
+
+#[test]
+fn scalars_compare_in_constant_time() {
+    assert!(Scalar::from_u64(5).ct_eq(&Scalar::from_u64(5)));
+    assert!(!Scalar::from_u64(5).ct_eq(&Scalar::from_u64(6)));
+    assert_eq!(Scalar::from_u64(1), Scalar::ONE);
+    assert_ne!(Scalar::ONE, Scalar::ZERO);
+}
+
+#[test]
+fn public_values_print_hex() {
+    let bytes: [u8; 12] = std::array::from_fn(|i| i as u8 * 17);
+
+    assert_eq!(
+        format!("{:?}", Nonce::new(bytes)),
+        "Nonce(00112233445566778899aabb)"
+    );
+    assert_eq!(
+        format!("{:?}", StreamId::new(bytes)),
+        "StreamId(00112233445566778899aabb)"
+    );
+    assert_eq!(
+        format!("{:?}", Constants::default()),
+        "Constants(657870616e642033322d62797465206b)"
+    );
+    assert_eq!(
+        format!("{:?}", XNonce::new([0xff; 24])),
+        format!("XNonce({})", "ff".repeat(24))
+    );
+    assert_eq!(
+        format!("{:?}", Tag::new([1; 16])),
+        format!("Tag({})", "01".repeat(16))
+    );
+    assert_eq!(
+        format!("{:?}", PublicKey::new([9; 32])),
+        format!("PublicKey({})", "09".repeat(32))
+    );
+}
@@ -0,0 +1,99 @@
+use hex_literal::hex;
+use purecrypt::ed25519::{
+    Error, Signature, SigningKey, VerificationMode, VerifyingKey, verify_batch,
+};
+
+// canonical encodings of the eight 8-torsion points followed by
+// non-canonical encodings of small-order points
+const SMALL_ORDER_ENCODINGS: [[u8; 32]; 14] = [
+    hex!("0100000000000000000000000000000000000000000000000000000000000000"),
+    hex!("ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f"),
+    hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+    hex!("0000000000000000000000000000000000000000000000000000000000000080"),
+    hex!("c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac037a"),
+    hex!("c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac03fa"),
+    hex!("26e8958fc2b227b045c3f489f2ef98f0d5dfac05d3c63339b13802886d53fc05"),
+    hex!("26e8958fc2b227b045c3f489f2ef98f0d5dfac05d3c63339b13802886d53fc85"),
+    hex!("0100000000000000000000000000000000000000000000000000000000000080"),
+    hex!("ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+    hex!("edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f"),
+    hex!("edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+    hex!("eeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f"),
+    hex!("eeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+];
+
+fn keys_and_signatures(count: u8) -> (Vec<Vec<u8>>, Vec<Signature>, Vec<VerifyingKey>) {
+    let mut messages = Vec::new();
+    let mut signatures = Vec::new();
+    let mut keys = Vec::new();
+
+    for i in 0..count {
+        let signing_key = SigningKey::from_bytes(&[i; 32]);
+        let message = vec![i; usize::from(i) * 3];
+
+        signatures.push(signing_key.sign(&message));
+        keys.push(*signing_key.verifying_key());
+        messages.push(message);
+    }
+
+    (messages, signatures, keys)
+}
+
+#[test]
+fn ed25519_batch_accepts_valid_signatures() {
+    let (messages, signatures, keys) = keys_and_signatures(7);
+    let messages: Vec<&[u8]> = messages.iter().map(Vec::as_slice).collect();
+
+    assert_eq!(verify_batch(&messages, &signatures, &keys), Ok(()));
+    assert_eq!(verify_batch(&[], &[], &[]), Ok(()));
+}
+
+#[test]
+fn ed25519_batch_rejects_any_invalid_signature() {
+    let (messages, signatures, keys) = keys_and_signatures(7);
+    let mut messages: Vec<&[u8]> = messages.iter().map(Vec::as_slice).collect();
+
+    messages[3] = b"tampered";
+    assert_eq!(
+        verify_batch(&messages, &signatures, &keys),
+        Err(Error::InvalidSignature)
+    );
+
+    assert_eq!(
+        verify_batch(&messages[1..], &signatures, &keys),
+        Err(Error::BatchLengthMismatch)
+    );
+}
+
+#[test]
+fn ed25519_zip215_small_order_cases() {
+    // every (A, R) pair of small-order points with S = 0 satisfies the
+    // cofactored equation but none passes the strict rules
+    let message = b"Zcash";
+    let mut messages = Vec::new();
+    let mut signatures = Vec::new();
+    let mut keys = Vec::new();
+
+    for a in &SMALL_ORDER_ENCODINGS {
+        let verifying_key = VerifyingKey::from_bytes(a).unwrap();
+        assert!(verifying_key.is_weak());
+
+        for r in &SMALL_ORDER_ENCODINGS {
+            let mut bytes = [0; 64];
+            bytes[..32].copy_from_slice(r);
+            let signature = Signature::new(bytes);
+
+            assert_eq!(
+                verifying_key.verify_with_mode(message, &signature, VerificationMode::Zip215),
+                Ok(())
+            );
+            assert!(verifying_key.verify(message, &signature).is_err());
+
+            messages.push(&message[..]);
+            signatures.push(signature);
+            keys.push(verifying_key);
+        }
+    }
+
+    assert_eq!(verify_batch(&messages, &signatures, &keys), Ok(()));
+}
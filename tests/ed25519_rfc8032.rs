@@ -0,0 +1,213 @@
+use hex_literal::hex;
+use purecrypt::ed25519::{Error, Signature, SigningKey, VerificationMode, VerifyingKey};
+use purecrypt::sha2::Sha512;
+
+fn check_pure(secret: [u8; 32], public: [u8; 32], message: &[u8], signature: [u8; 64]) {
+    let signing_key = SigningKey::from_bytes(&secret);
+    assert_eq!(signing_key.verifying_key().as_bytes(), &public);
+
+    let signature = Signature::new(signature);
+    assert_eq!(signing_key.sign(message), signature);
+
+    let verifying_key = VerifyingKey::from_bytes(&public).unwrap();
+    assert_eq!(verifying_key.verify(message, &signature), Ok(()));
+    assert_eq!(
+        verifying_key.verify_with_mode(message, &signature, VerificationMode::Zip215),
+        Ok(())
+    );
+}
+
+fn check_context(
+    secret: [u8; 32],
+    public: [u8; 32],
+    message: &[u8],
+    context: &[u8],
+    signature: [u8; 64],
+) {
+    let signing_key = SigningKey::from_bytes(&secret);
+    assert_eq!(signing_key.verifying_key().as_bytes(), &public);
+
+    let signature = Signature::new(signature);
+    assert_eq!(
+        signing_key.sign_with_context(message, context),
+        Ok(signature)
+    );
+
+    let verifying_key = VerifyingKey::from_bytes(&public).unwrap();
+    assert_eq!(
+        verifying_key.verify_with_context(message, context, &signature),
+        Ok(())
+    );
+}
+
+#[test]
+fn ed25519_rfc8032() {
+    // RFC 8032 section 7.1 test vectors:
+    // https://www.rfc-editor.org/rfc/rfc8032#section-7.1
+    check_pure(
+        hex!("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60"),
+        hex!("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"),
+        b"",
+        hex!(
+            "e5564300c360ac729086e2cc806e828a
+             84877f1eb8e5d974d873e06522490155
+             5fb8821590a33bacc61e39701cf9b46b
+             d25bf5f0595bbe24655141438e7a100b"
+        ),
+    );
+
+    check_pure(
+        hex!("4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb"),
+        hex!("3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c"),
+        &hex!("72"),
+        hex!(
+            "92a009a9f0d4cab8720e820b5f642540
+             a2b27b5416503f8fb3762223ebdb69da
+             085ac1e43e15996e458f3613d0f11d8c
+             387b2eaeb4302aeeb00d291612bb0c00"
+        ),
+    );
+
+    check_pure(
+        hex!("c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7"),
+        hex!("fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025"),
+        &hex!("af82"),
+        hex!(
+            "6291d657deec24024827e69c3abe01a3
+             0ce548a284743a445e3680d7db5ac3ac
+             18ff9b538d16f290ae67f760984dc659
+             4a7c15e9716ed28dc027beceea1ec40a"
+        ),
+    );
+
+    // TEST SHA(abc)
+    check_pure(
+        hex!("833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42"),
+        hex!("ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf"),
+        &Sha512::digest(b"abc"),
+        hex!(
+            "dc2a4459e7369633a52b1bf277839a00
+             201009a3efbf3ecb69bea2186c26b589
+             09351fc9ac90b3ecfdfbc7c66431e030
+             3dca179c138ac17ad9bef1177331a704"
+        ),
+    );
+}
+
+#[test]
+fn ed25519ctx_rfc8032() {
+    // RFC 8032 section 7.2 test vectors:
+    // https://www.rfc-editor.org/rfc/rfc8032#section-7.2
+    let secret = hex!("0305334e381af78f141cb666f6199f57bc3495335a256a95bd2a55bf546663f6");
+    let public = hex!("dfc9425e4f968f7f0c29f0259cf5f9aed6851c2bb4ad8bfb860cfee0ab248292");
+
+    check_context(
+        secret,
+        public,
+        &hex!("f726936d19c800494e3fdaff20b276a8"),
+        b"foo",
+        hex!(
+            "55a4cc2f70a54e04288c5f4cd1e45a7b
+             b520b36292911876cada7323198dd87a
+             8b36950b95130022907a7fb7c4e9b2d5
+             f6cca685a587b4b21f4b888e4e7edb0d"
+        ),
+    );
+
+    check_context(
+        secret,
+        public,
+        &hex!("f726936d19c800494e3fdaff20b276a8"),
+        b"bar",
+        hex!(
+            "fc60d5872fc46b3aa69f8b5b4351d580
+             8f92bcc044606db097abab6dbcb1aee3
+             216c48e8b3b66431b5b186d1d28f8ee1
+             5a5ca2df6668346291c2043d4eb3e90d"
+        ),
+    );
+
+    check_context(
+        secret,
+        public,
+        &hex!("508e9e6882b979fea900f62adceaca35"),
+        b"foo",
+        hex!(
+            "8b70c1cc8310e1de20ac53ce28ae6e72
+             07f33c3295e03bb5c0732a1d20dc6490
+             8922a8b052cf99b7c4fe107a5abb5b2c
+             4085ae75890d02df26269d8945f84b0b"
+        ),
+    );
+
+    check_context(
+        hex!("ab9c2853ce297ddab85c993b3ae14bcad39b2c682beabc27d6d4eb20711d6560"),
+        hex!("0f1d1274943b91415889152e893d80e93275a1fc0b65fd71b4b0dda10ad7d772"),
+        &hex!("f726936d19c800494e3fdaff20b276a8"),
+        b"foo",
+        hex!(
+            "21655b5f1aa965996b3f97b3c849eafb
+             a922a0a62992f73b3d1b73106a84ad85
+             e9b86a7b6005ea868337ff2d20a7f5fb
+             d4cd10b0be49a68da2b2e0dc0ad8960f"
+        ),
+    );
+}
+
+#[test]
+fn ed25519ph_rfc8032() {
+    // RFC 8032 section 7.3 test vector:
+    // https://www.rfc-editor.org/rfc/rfc8032#section-7.3
+    let signing_key = SigningKey::from_bytes(&hex!(
+        "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42"
+    ));
+    let expected = Signature::new(hex!(
+        "98a70222f0b8121aa9d30f813d683f80
+         9e462b469c7ff87639499bb94e6dae41
+         31f85042463c2a355a2003d062adf5aa
+         a10b8c61e636062aaad11c2a26083406"
+    ));
+
+    let mut prehashed = Sha512::new();
+    prehashed.update(b"abc");
+
+    let signature = signing_key.sign_prehashed(prehashed.clone(), b"").unwrap();
+    assert_eq!(signature, expected);
+
+    let verifying_key = signing_key.verifying_key();
+    assert_eq!(
+        verifying_key.verify_prehashed(prehashed, b"", &signature),
+        Ok(())
+    );
+
+    // a prehashed signature is not a plain signature over the digest
+    assert_eq!(
+        verifying_key.verify(&Sha512::digest(b"abc"), &signature),
+        Err(Error::InvalidSignature)
+    );
+}
+
+#[test]
+fn ed25519_context_is_bound() {
+    let signing_key = SigningKey::from_bytes(&[0x42; 32]);
+    let verifying_key = signing_key.verifying_key();
+
+    let signature = signing_key.sign_with_context(b"message", b"foo").unwrap();
+    assert_eq!(
+        verifying_key.verify_with_context(b"message", b"bar", &signature),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        verifying_key.verify(b"message", &signature),
+        Err(Error::InvalidSignature)
+    );
+
+    assert_eq!(
+        signing_key.sign_with_context(b"message", b""),
+        Err(Error::InvalidContext)
+    );
+    assert_eq!(
+        signing_key.sign_with_context(b"message", &[0; 256]),
+        Err(Error::InvalidContext)
+    );
+}
@@ -0,0 +1,71 @@
+use purecrypt::ed25519::{Signature, VerificationMode, VerifyingKey};
+use wycheproof::TestResult;
+use wycheproof::eddsa::{TestName, TestSet};
+
+fn verify(public: &[u8], message: &[u8], signature: &[u8], mode: VerificationMode) -> bool {
+    let (Ok(public), Ok(signature)) = (
+        <[u8; 32]>::try_from(public),
+        <[u8; 64]>::try_from(signature),
+    ) else {
+        return false;
+    };
+
+    let Ok(verifying_key) = VerifyingKey::from_bytes(&public) else {
+        return false;
+    };
+
+    verifying_key
+        .verify_with_mode(message, &Signature::new(signature), mode)
+        .is_ok()
+}
+
+#[test]
+fn ed25519_wycheproof_strict() {
+    // Wycheproof EdDSA verification vectors:
+    // https://github.com/C2SP/wycheproof/blob/main/testvectors_v1/ed25519_test.json
+    let test_set = TestSet::load(TestName::Ed25519).unwrap();
+
+    for group in test_set.test_groups {
+        for test in group.tests {
+            let accepted = verify(
+                &group.key.pk,
+                &test.msg,
+                &test.sig,
+                VerificationMode::Strict,
+            );
+
+            assert_eq!(
+                accepted,
+                test.result == TestResult::Valid,
+                "tcId {} ({})",
+                test.tc_id,
+                test.comment
+            );
+        }
+    }
+}
+
+#[test]
+fn ed25519_wycheproof_zip215_accepts_valid() {
+    let test_set = TestSet::load(TestName::Ed25519).unwrap();
+
+    for group in test_set.test_groups {
+        for test in group.tests {
+            if test.result != TestResult::Valid {
+                continue;
+            }
+
+            assert!(
+                verify(
+                    &group.key.pk,
+                    &test.msg,
+                    &test.sig,
+                    VerificationMode::Zip215
+                ),
+                "tcId {} ({})",
+                test.tc_id,
+                test.comment
+            );
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use purecrypt::Error;
+use purecrypt::chacha::{Key, XNonce};
+use purecrypt::utils::{
+    decode_base64, decode_base64_url, decode_hex, encode_base64, encode_base64_url, encode_hex,
+};
+
+// RFC 4648 section 10
+const VECTORS: [(&str, &str, &str); 7] = [
+    ("", "", ""),
+    ("f", "Zg==", "666f"),
+    ("fo", "Zm8=", "666f6f"),
+    ("foo", "Zm9v", "666f6f"),
+    ("foob", "Zm9vYg==", "666f6f62"),
+    ("fooba", "Zm9vYmE=", "666f6f6261"),
+    ("foobar", "Zm9vYmFy", "666f6f626172"),
+];
+
+#[test]
+fn rfc4648_base64() {
+    for (plain, base64, _) in VECTORS {
+        assert_eq!(encode_base64(plain.as_bytes()), base64);
+        assert_eq!(decode_base64(base64).unwrap(), plain.as_bytes());
+
+        let url = base64.trim_end_matches('=');
+        assert_eq!(encode_base64_url(plain.as_bytes()), url);
+        assert_eq!(decode_base64_url(url).unwrap(), plain.as_bytes());
+    }
+}
+
+#[test]
+fn rfc4648_base16() {
+    for (plain, _, _) in VECTORS {
+        let hex: String = plain.bytes().map(|b| format!("{b:02x}")).collect();
+
+        assert_eq!(encode_hex(plain.as_bytes()), hex);
+        assert_eq!(decode_hex(&hex).unwrap(), plain.as_bytes());
+        assert_eq!(decode_hex(&hex.to_uppercase()).unwrap(), plain.as_bytes());
+    }
+}
+
+#[test]
+fn every_byte_round_trips() {
+    let bytes: Vec<u8> = (0..=255).collect();
+
+    for len in 0..bytes.len() {
+        let bytes = &bytes[len..];
+
+        assert_eq!(decode_hex(&encode_hex(bytes)).unwrap(), bytes);
+        assert_eq!(decode_base64(&encode_base64(bytes)).unwrap(), bytes);
+        assert_eq!(decode_base64_url(&encode_base64_url(bytes)).unwrap(), bytes);
+    }
+}
+
+#[test]
+fn alphabets_differ_in_two_characters() {
+    let bytes = [0xfb, 0xff, 0xbf];
+
+    assert_eq!(encode_base64(&bytes), "+/+/");
+    assert_eq!(encode_base64_url(&bytes), "-_-_");
+    assert_eq!(decode_base64("-_-_"), Err(Error::InvalidEncoding));
+    assert_eq!(decode_base64_url("+/+/"), Err(Error::InvalidEncoding));
+}
+
+#[test]
+fn malformed_input_is_rejected() {
+    for hex in ["0", "0g", "g0", "0:", "@0", "`0", "0G", " 00"] {
+        assert_eq!(decode_hex(hex), Err(Error::InvalidEncoding), "{hex:?}");
+    }
+
+    for base64 in [
+        "Zg=", "Zg", "Z===", "Zg=A", "Zh==", "Zm9=", "Zm8", "Zm9v\n", "Zm 9", "=Zm9",
+    ] {
+        assert_eq!(
+            decode_base64(base64),
+            Err(Error::InvalidEncoding),
+            "{base64:?}"
+        );
+    }
+
+    for base64 in ["Zg==", "Z", "Zh", "Zm9", "Zm9v=", "Zm+v"] {
+        assert_eq!(
+            decode_base64_url(base64),
+            Err(Error::InvalidEncoding),
+            "{base64:?}"
+        );
+    }
+}
+
+#[test]
+fn wrappers_text_forms() {
+    let bytes: [u8; 32] = std::array::from_fn(|i| i as u8 * 7);
+    let key = Key::new(bytes);
+
+    let hex = key.to_hex();
+    assert_eq!(hex, encode_hex(&bytes));
+    assert_eq!(Key::from_hex(&hex).unwrap(), key);
+    assert_eq!(hex.parse::<Key>().unwrap(), key);
+
+    assert_eq!(Key::from_base64(&key.to_base64()).unwrap(), key);
+    assert_eq!(Key::from_base64_url(&key.to_base64_url()).unwrap(), key);
+
+    let nonce = XNonce::new([0xfb; 24]);
+    assert_eq!(nonce.to_base64(), "+/v7".repeat(8));
+    assert_eq!(nonce.to_base64_url(), "-_v7".repeat(8));
+    assert_eq!(XNonce::from_base64(&"+/v7".repeat(8)).unwrap(), nonce);
+}
+
+#[test]
+fn wrappers_check_length() {
+    assert_eq!(
+        Key::from_hex("00"),
+        Err(Error::InvalidLength {
+            expected: 32,
+            actual: 1
+        })
+    );
+    assert_eq!(
+        XNonce::from_base64("AAAA"),
+        Err(Error::InvalidLength {
+            expected: 24,
+            actual: 3
+        })
+    );
+    assert_eq!(Key::from_hex(&"zz".repeat(32)), Err(Error::InvalidEncoding));
+}
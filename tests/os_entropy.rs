@@ -0,0 +1,43 @@
+use purecrypt::chacha::{ChaCha20Rng, DjbChaCha20Rng, Seed};
+use purecrypt::os;
+
+#[test]
+fn fill_covers_large_buffers() {
+    // larger than a single getrandom call returns at once
+    let mut buf = vec![0; 1 << 26];
+    os::fill(&mut buf).unwrap();
+
+    // a zero byte in every 4 KiB page would be 2^-4096 likely
+    assert!(buf.chunks(4096).all(|page| page.iter().any(|&b| b != 0)));
+}
+
+#[test]
+fn fill_empty_is_ok() {
+    os::fill(&mut []).unwrap();
+}
+
+#[test]
+fn seeds_and_rngs_from_entropy_differ() {
+    assert_ne!(Seed::from_os().unwrap(), Seed::from_os().unwrap());
+
+    let mut a = [0; 64];
+    let mut b = [0; 64];
+    ChaCha20Rng::from_entropy().unwrap().fill_bytes(&mut a);
+    ChaCha20Rng::from_entropy().unwrap().fill_bytes(&mut b);
+    assert_ne!(a, b);
+
+    DjbChaCha20Rng::from_entropy().unwrap().fill_bytes(&mut a);
+    assert_ne!(a, b);
+}
+
+#[test]
+fn error_messages() {
+    assert_eq!(
+        os::Error::Os(38).to_string(),
+        "os entropy source failed with error 38"
+    );
+    assert_eq!(
+        os::Error::Unsupported.to_string(),
+        "no os entropy source on this platform"
+    );
+}
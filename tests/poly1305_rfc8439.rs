@@ -0,0 +1,82 @@
+use hex_literal::hex;
+use purecrypt::poly1305::Poly1305;
+
+fn check(key: [u8; 32], message: &[u8], tag: [u8; 16]) {
+    assert_eq!(Poly1305::mac(&key, message), tag);
+
+    // the result does not depend on how the input is split
+    for step in [1, 3, 15, 16, 17, 33] {
+        let mut poly = Poly1305::new(&key);
+        message.chunks(step).for_each(|chunk| poly.update(chunk));
+        assert_eq!(poly.finalize(), tag, "step {step}");
+    }
+}
+
+#[test]
+fn poly1305_rfc8439_section_2_5_2() {
+    check(
+        hex!(
+            "85d6be7857556d337f4452fe42d506a8"
+            "0103808afb0db2fd4abff6af4149f51b"
+        ),
+        b"Cryptographic Forum Research Group",
+        hex!("a8061dc1305136c6c22b8baf0c0127a9"),
+    );
+}
+
+#[test]
+fn poly1305_rfc8439_appendix_a_3() {
+    // test vector 1
+    check([0; 32], &[0; 64], [0; 16]);
+
+    // test vectors 5 to 9 exercise the carries and the final reduction
+    check(
+        hex!("02000000000000000000000000000000" "00000000000000000000000000000000"),
+        &hex!("ffffffffffffffffffffffffffffffff"),
+        hex!("03000000000000000000000000000000"),
+    );
+    check(
+        hex!("02000000000000000000000000000000" "ffffffffffffffffffffffffffffffff"),
+        &hex!("02000000000000000000000000000000"),
+        hex!("03000000000000000000000000000000"),
+    );
+    check(
+        hex!("01000000000000000000000000000000" "00000000000000000000000000000000"),
+        &hex!(
+            "ffffffffffffffffffffffffffffffff"
+            "f0ffffffffffffffffffffffffffffff"
+            "11000000000000000000000000000000"
+        ),
+        hex!("05000000000000000000000000000000"),
+    );
+    check(
+        hex!("01000000000000000000000000000000" "00000000000000000000000000000000"),
+        &hex!(
+            "ffffffffffffffffffffffffffffffff"
+            "fbfefefefefefefefefefefefefefefe"
+            "01010101010101010101010101010101"
+        ),
+        hex!("00000000000000000000000000000000"),
+    );
+    check(
+        hex!("02000000000000000000000000000000" "00000000000000000000000000000000"),
+        &hex!("fdffffffffffffffffffffffffffffff"),
+        hex!("faffffffffffffffffffffffffffffff"),
+    );
+}
+
+#[test]
+fn poly1305_update_padded() {
+    let key = [0x5a; 32];
+
+    let mut padded = Poly1305::new(&key);
+    padded.update_padded(b"abc");
+    padded.update_padded(&[]);
+    padded.update_padded(&[7; 16]);
+
+    let mut expected = b"abc".to_vec();
+    expected.resize(16, 0);
+    expected.extend_from_slice(&[7; 16]);
+
+    assert_eq!(padded.finalize(), Poly1305::mac(&key, &expected));
+}
@@ -0,0 +1,59 @@
+use purecrypt::aes::{Key128, Key192, Key256};
+use purecrypt::chacha::djb::Nonce as DjbNonce;
+use purecrypt::chacha::ietf::{Nonce as IetfNonce, StreamId};
+use purecrypt::chacha::{Key, Seed, XNonce};
+use purecrypt::x25519::{EphemeralSecret, StaticSecret};
+
+#[test]
+fn random_values_differ() {
+    assert_ne!(Key::random(), Key::random());
+    assert_ne!(Seed::random(), Seed::random());
+    assert_ne!(Key128::random(), Key128::random());
+    assert_ne!(Key192::random(), Key192::random());
+    assert_ne!(Key256::random(), Key256::random());
+    assert_ne!(XNonce::random(), XNonce::random());
+    assert_ne!(IetfNonce::random(), IetfNonce::random());
+    assert_ne!(StreamId::random(), StreamId::random());
+    assert_ne!(DjbNonce::random(), DjbNonce::random());
+    assert_ne!(
+        purecrypt::aes_gcm::Nonce::random(),
+        purecrypt::aes_gcm::Nonce::random()
+    );
+
+    assert_ne!(StaticSecret::random(), StaticSecret::random());
+    assert_ne!(
+        EphemeralSecret::random().public_key(),
+        EphemeralSecret::random().public_key()
+    );
+}
+
+#[test]
+fn try_random_succeeds_with_os_entropy() {
+    assert_ne!(Key::try_random().unwrap(), Key::try_random().unwrap());
+    assert_ne!(
+        StaticSecret::try_random().unwrap(),
+        StaticSecret::try_random().unwrap()
+    );
+    assert_ne!(
+        EphemeralSecret::try_random().unwrap().public_key(),
+        EphemeralSecret::try_random().unwrap().public_key()
+    );
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_from_rng_draws_from_the_rng() {
+    use purecrypt::chacha::ChaCha20Rng;
+
+    let seed = Seed::new([7; 32]);
+
+    let mut expected = [0; 32 + 24];
+    ChaCha20Rng::from_seed(&seed).fill_bytes(&mut expected);
+
+    let mut rng = ChaCha20Rng::from_seed(&seed);
+    let key = Key::random_from_rng(&mut rng);
+    let nonce = XNonce::random_from_rng(&mut rng);
+
+    assert_eq!(key.bytes(), &expected[..32]);
+    assert_eq!(nonce.bytes(), &expected[32..]);
+}
@@ -0,0 +1,142 @@
+use hex_literal::hex;
+use purecrypt::pbkdf2::pbkdf2_hmac_sha256;
+use purecrypt::salsa::salsa20_8;
+use purecrypt::scrypt::{Error, Params, scrypt};
+
+fn scrypt_hex(password: &[u8], salt: &[u8], log_n: u8, r: u32, p: u32) -> [u8; 64] {
+    let params = Params::new(log_n, r, p).unwrap();
+    let mut out = [0u8; 64];
+    scrypt(password, salt, &params, &mut out).unwrap();
+    out
+}
+
+#[test]
+fn salsa20_8_core_rfc7914() {
+    // RFC 7914 section 8 test vector:
+    // https://www.rfc-editor.org/rfc/rfc7914#section-8
+    let input = hex!(
+        "7e879a214f3ec9867ca940e641718f26
+         baee555b8c61c1b50df846116dcd3b1d
+         ee24f319df9b3d8514121e4b5ac5aa32
+         76021d2909c74829edebc68db8b8c25e"
+    );
+    let expected = hex!(
+        "a41f859c6608cc993b81cacb020cef05
+         044b2181a2fd337dfd7b1c6396682f29
+         b4393168e3c9e6bcfe6bc5b7a06d96ba
+         e424cc102c91745c24ad673dc7618f81"
+    );
+
+    let mut state = [0u32; 16];
+    for (word, chunk) in state.iter_mut().zip(input.chunks_exact(4)) {
+        *word = u32::from_le_bytes(chunk.try_into().unwrap());
+    }
+
+    salsa20_8(&mut state);
+
+    let out: Vec<u8> = state.iter().flat_map(|w| w.to_le_bytes()).collect();
+    assert_eq!(out.as_slice(), expected.as_slice());
+}
+
+#[test]
+fn pbkdf2_hmac_sha256_rfc7914() {
+    // RFC 7914 section 11 test vectors:
+    // https://www.rfc-editor.org/rfc/rfc7914#section-11
+    let mut out = [0u8; 64];
+    pbkdf2_hmac_sha256(b"passwd", b"salt", 1, &mut out);
+    assert_eq!(
+        out,
+        hex!(
+            "55ac046e56e3089fec1691c22544b605
+             f94185216dde0465e68b9d57c20dacbc
+             49ca9cccf179b645991664b39d77ef31
+             7c71b845b1e30bd509112041d3a19783"
+        )
+    );
+
+    pbkdf2_hmac_sha256(b"Password", b"NaCl", 80000, &mut out);
+    assert_eq!(
+        out,
+        hex!(
+            "4ddcd8f60b98be21830cee5ef22701f9
+             641a4418d04c0414aeff08876b34ab56
+             a1d425a1225833549adb841b51c9b317
+             6a272bdebba1d078478f62b397f33c8d"
+        )
+    );
+}
+
+#[test]
+fn scrypt_rfc7914() {
+    // RFC 7914 section 12 test vectors:
+    // https://www.rfc-editor.org/rfc/rfc7914#section-12
+    assert_eq!(
+        scrypt_hex(b"", b"", 4, 1, 1),
+        hex!(
+            "77d6576238657b203b19ca42c18a0497
+             f16b4844e3074ae8dfdffa3fede21442
+             fcd0069ded0948f8326a753a0fc81f17
+             e8d3e0fb2e0d3628cf35e20c38d18906"
+        )
+    );
+
+    assert_eq!(
+        scrypt_hex(b"password", b"NaCl", 10, 8, 16),
+        hex!(
+            "fdbabe1c9d3472007856e7190d01e9fe
+             7c6ad7cbc8237830e77376634b373162
+             2eaf30d92e22a3886ff109279d9830da
+             c727afb94a83ee6d8360cbdfa2cc0640"
+        )
+    );
+
+    assert_eq!(
+        scrypt_hex(b"pleaseletmein", b"SodiumChloride", 14, 8, 1),
+        hex!(
+            "7023bdcb3afd7348461c06cd81fd38eb
+             fda8fbba904f8e3ea9b543f6545da1f2
+             d5432955613f0fcf62d49705242a9af9
+             e61e85dc0d651e40dfcf017b45575887"
+        )
+    );
+}
+
+#[test]
+#[ignore = "needs 1 GiB of memory"]
+fn scrypt_rfc7914_large() {
+    assert_eq!(
+        scrypt_hex(b"pleaseletmein", b"SodiumChloride", 20, 8, 1),
+        hex!(
+            "2101cb9b6a511aaeaddbbe09cf70f881
+             ec568d574a2ffd4dabe5ee9820adaa47
+             8e56fd8f4ba5d09ffa1c6d927c40f4c3
+             37304049e8a952fbcbf45c6fa77a41a4"
+        )
+    );
+}
+
+#[test]
+fn scrypt_short_output_is_prefix() {
+    let params = Params::new(4, 1, 1).unwrap();
+    let mut short = [0u8; 16];
+    scrypt(b"", b"", &params, &mut short).unwrap();
+
+    assert_eq!(short, hex!("77d6576238657b203b19ca42c18a0497"));
+}
+
+#[test]
+fn scrypt_rejects_invalid_params() {
+    assert_eq!(Params::new(0, 8, 1), Err(Error::InvalidLogN));
+    assert_eq!(Params::new(16, 1, 1), Err(Error::InvalidLogN));
+    assert_eq!(Params::new(64, 8, 1), Err(Error::InvalidLogN));
+    assert_eq!(Params::new(10, 0, 1), Err(Error::InvalidR));
+    assert_eq!(Params::new(10, 8, 0), Err(Error::InvalidP));
+    assert_eq!(Params::new(10, 1 << 15, 1 << 15), Err(Error::InvalidP));
+    assert!(Params::new(15, 1, 1).is_ok());
+
+    let params = Params::new(4, 1, 1).unwrap();
+    assert_eq!(
+        scrypt(b"", b"", &params, &mut []),
+        Err(Error::InvalidOutputLen)
+    );
+}
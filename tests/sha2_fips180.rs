@@ -0,0 +1,115 @@
+use hex_literal::hex;
+use purecrypt::hmac::HmacSha256;
+use purecrypt::sha2::{Sha256, Sha512};
+
+#[test]
+fn sha256_fips180_examples() {
+    // FIPS 180-2 appendix B examples:
+    // https://csrc.nist.gov/publications/detail/fips/180/2/archive/2002-08-01
+    assert_eq!(
+        Sha256::digest(b"abc"),
+        hex!("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+    );
+
+    assert_eq!(
+        Sha256::digest(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+        hex!("248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1")
+    );
+
+    let mut hasher = Sha256::new();
+    let chunk = [b'a'; 1000];
+    for _ in 0..1000 {
+        hasher.update(&chunk);
+    }
+    assert_eq!(
+        hasher.finalize(),
+        hex!("cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0")
+    );
+}
+
+#[test]
+fn sha256_chunked_update_matches_one_shot() {
+    let data: Vec<u8> = (0..300).map(|i| (i * 7) as u8).collect();
+
+    for split in [0, 1, 55, 56, 63, 64, 65, 128, 299] {
+        let mut hasher = Sha256::new();
+        hasher.update(&data[..split]);
+        hasher.update(&data[split..]);
+
+        assert_eq!(hasher.finalize(), Sha256::digest(&data));
+    }
+}
+
+#[test]
+fn sha512_fips180_examples() {
+    // FIPS 180-2 appendix C examples:
+    // https://csrc.nist.gov/publications/detail/fips/180/2/archive/2002-08-01
+    assert_eq!(
+        Sha512::digest(b"abc"),
+        hex!(
+            "ddaf35a193617abacc417349ae204131
+             12e6fa4e89a97ea20a9eeee64b55d39a
+             2192992a274fc1a836ba3c23a3feebbd
+             454d4423643ce80e2a9ac94fa54ca49f"
+        )
+    );
+
+    assert_eq!(
+        Sha512::digest(
+            b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmn\
+              hijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu"
+        ),
+        hex!(
+            "8e959b75dae313da8cf4f72814fc143f
+             8f7779c6eb9f7fa17299aeadb6889018
+             501d289e4900f7e4331b99dec4b5433a
+             c7d329eeb6dd26545e96e55b874be909"
+        )
+    );
+
+    let mut hasher = Sha512::new();
+    let chunk = [b'a'; 1000];
+    for _ in 0..1000 {
+        hasher.update(&chunk);
+    }
+    assert_eq!(
+        hasher.finalize(),
+        hex!(
+            "e718483d0ce769644e2e42c7bc15b463
+             8e1f98b13b2044285632a803afa973eb
+             de0ff244877ea60a4cb0432ce577c31b
+             eb009c5c2c49aa2e4eadb217ad8cc09b"
+        )
+    );
+}
+
+#[test]
+fn sha512_chunked_update_matches_one_shot() {
+    let data: Vec<u8> = (0..600).map(|i| (i * 7) as u8).collect();
+
+    for split in [0, 1, 111, 112, 127, 128, 129, 256, 599] {
+        let mut hasher = Sha512::new();
+        hasher.update(&data[..split]);
+        hasher.update(&data[split..]);
+
+        assert_eq!(hasher.finalize(), Sha512::digest(&data));
+    }
+}
+
+#[test]
+fn hmac_sha256_rfc4231() {
+    // RFC 4231 test cases 1 and 6:
+    // https://www.rfc-editor.org/rfc/rfc4231#section-4
+    assert_eq!(
+        HmacSha256::mac(&[0x0b; 20], b"Hi There"),
+        hex!("b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7")
+    );
+
+    assert_eq!(
+        HmacSha256::mac(
+            &[0xaa; 131],
+            b"Test Using Larger Than Block-Size Key - Hash Key First"
+        ),
+        hex!("60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54")
+    );
+}
@@ -0,0 +1,123 @@
+use purecrypt::Error;
+use purecrypt::aes::{Aes128, Aes256, BlockCipher, Key128, Key192, Key256};
+use purecrypt::chacha::djb::{Counter, DjbChaCha20, Nonce as DjbNonce};
+use purecrypt::chacha::ietf::{Nonce as IetfNonce, StreamId};
+use purecrypt::chacha::{
+    ChaCha20, ChaCha20Rng, Constants, DjbChaCha20Rng, Key, Seed, XChaCha20, XNonce,
+};
+
+fn check<T: for<'a> TryFrom<&'a [u8], Error = Error> + AsRef<[u8]>>(len: usize) {
+    let bytes: Vec<u8> = (0..len as u8 + 1).collect();
+
+    let value = T::try_from(&bytes[..len]).unwrap();
+    assert_eq!(value.as_ref(), &bytes[..len]);
+
+    for bad in [0, len - 1, len + 1] {
+        assert_eq!(
+            T::try_from(&bytes[..bad]).err(),
+            Some(Error::InvalidLength {
+                expected: len,
+                actual: bad,
+            })
+        );
+    }
+}
+
+#[test]
+fn wrappers_try_from_slices() {
+    check::<Key>(32);
+    check::<Seed>(32);
+    check::<Constants>(16);
+    check::<IetfNonce>(12);
+    check::<StreamId>(12);
+    check::<DjbNonce>(8);
+    check::<Counter>(8);
+    check::<XNonce>(24);
+    check::<Key128>(16);
+    check::<Key192>(24);
+    check::<Key256>(32);
+    check::<purecrypt::aes_gcm::Nonce>(12);
+    check::<purecrypt::aead::Tag>(16);
+    check::<purecrypt::x25519::PublicKey>(32);
+    check::<purecrypt::ed25519::Signature>(64);
+    check::<purecrypt::ristretto255::CompressedRistretto>(32);
+}
+
+#[test]
+fn ciphers_new_from_slices() {
+    let key = [7; 32];
+
+    let mut a = [0; 100];
+    let mut b = [0; 100];
+    ChaCha20::new_from_slices(&key, &[1; 12])
+        .unwrap()
+        .apply_keystream(&mut a);
+    ChaCha20::new(&Key::new(key), &IetfNonce::new([1; 12])).apply_keystream(&mut b);
+    assert_eq!(a, b);
+
+    DjbChaCha20::new_from_slices(&key, &[1; 8])
+        .unwrap()
+        .apply_keystream(&mut a);
+    DjbChaCha20::new(&Key::new(key), &DjbNonce::new([1; 8])).apply_keystream(&mut b);
+    assert_eq!(a, b);
+
+    XChaCha20::new_from_slices(&key, &[1; 24])
+        .unwrap()
+        .apply_keystream(&mut a);
+    XChaCha20::new(&Key::new(key), &XNonce::new([1; 24])).apply_keystream(&mut b);
+    assert_eq!(a, b);
+
+    let (mut x, mut y) = ([3; 16], [3; 16]);
+    Aes256::new_from_slice(&key).unwrap().encrypt_block(&mut x);
+    Aes256::new(&Key256::new(key)).encrypt_block(&mut y);
+    assert_eq!(x, y);
+
+    assert_eq!(
+        ChaCha20::new_from_slices(&key[..31], &[1; 12]).err(),
+        Some(Error::InvalidLength {
+            expected: 32,
+            actual: 31
+        })
+    );
+    assert_eq!(
+        XChaCha20::new_from_slices(&key, &[1; 12]).err(),
+        Some(Error::InvalidLength {
+            expected: 24,
+            actual: 12
+        })
+    );
+    assert!(Aes128::new_from_slice(&key).is_err());
+}
+
+#[test]
+fn rngs_new_from_slices() {
+    let seed = [9; 32];
+
+    let mut a = ChaCha20Rng::new_from_slices(&seed, &[2; 12]).unwrap();
+    let mut b = ChaCha20Rng::new(&Seed::new(seed), &StreamId::new([2; 12]));
+    let (mut x, mut y) = ([0; 80], [0; 80]);
+    a.fill_bytes(&mut x);
+    b.fill_bytes(&mut y);
+    assert_eq!(x, y);
+
+    let mut a = DjbChaCha20Rng::new_from_slices(&seed, 5).unwrap();
+    let mut b = DjbChaCha20Rng::new(&Seed::new(seed), 5);
+    a.fill_bytes(&mut x);
+    b.fill_bytes(&mut y);
+    assert_eq!(x, y);
+
+    assert!(ChaCha20Rng::new_from_slices(&seed, &[2; 8]).is_err());
+    assert!(DjbChaCha20Rng::new_from_slices(&seed[1..], 5).is_err());
+}
+
+#[test]
+fn error_display() {
+    let error = Error::InvalidLength {
+        expected: 32,
+        actual: 3,
+    };
+    assert_eq!(
+        error.to_string(),
+        "invalid length: expected 32 bytes, got 3"
+    );
+}
@@ -0,0 +1,135 @@
+use hex_literal::hex;
+use purecrypt::x25519::{BASEPOINT, Error, PublicKey, StaticSecret, x25519};
+
+fn iterate(iterations: usize) -> [u8; 32] {
+    let mut k = BASEPOINT;
+    let mut u = BASEPOINT;
+
+    for _ in 0..iterations {
+        let out = x25519(k, u);
+        u = k;
+        k = out;
+    }
+
+    k
+}
+
+#[test]
+fn x25519_rfc7748_scalar_mult() {
+    // RFC 7748 section 5.2 test vectors:
+    // https://www.rfc-editor.org/rfc/rfc7748#section-5.2
+    assert_eq!(
+        x25519(
+            hex!("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4"),
+            hex!("e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c"),
+        ),
+        hex!("c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552")
+    );
+
+    assert_eq!(
+        x25519(
+            hex!("4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d"),
+            hex!("e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493"),
+        ),
+        hex!("95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957")
+    );
+}
+
+#[test]
+fn x25519_rfc7748_iterated() {
+    assert_eq!(
+        iterate(1),
+        hex!("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079")
+    );
+
+    assert_eq!(
+        iterate(1_000),
+        hex!("684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51")
+    );
+}
+
+#[test]
+#[ignore = "takes minutes in debug builds"]
+fn x25519_rfc7748_iterated_million() {
+    assert_eq!(
+        iterate(1_000_000),
+        hex!("7c3911e0ab2586fd864497297e575e6f3bc601c0883c30df5f4dd2d24f665424")
+    );
+}
+
+#[test]
+fn x25519_rfc7748_diffie_hellman() {
+    // RFC 7748 section 6.1 test vectors:
+    // https://www.rfc-editor.org/rfc/rfc7748#section-6.1
+    let alice = StaticSecret::new(hex!(
+        "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a"
+    ));
+    let bob = StaticSecret::new(hex!(
+        "5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb"
+    ));
+
+    let alice_public = PublicKey::from(&alice);
+    let bob_public = PublicKey::from(&bob);
+
+    assert_eq!(
+        alice_public.bytes(),
+        &hex!("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+    );
+    assert_eq!(
+        bob_public.bytes(),
+        &hex!("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f")
+    );
+
+    let expected = hex!("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+    assert_eq!(
+        alice.diffie_hellman(&bob_public).unwrap().bytes(),
+        &expected
+    );
+    assert_eq!(
+        bob.diffie_hellman(&alice_public).unwrap().bytes(),
+        &expected
+    );
+}
+
+#[test]
+fn x25519_rejects_low_order_points() {
+    let secret = StaticSecret::new([0x42; 32]);
+
+    // points of order 1, 2, 4 and 8 together with their non-canonical forms
+    let low_order = [
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("0100000000000000000000000000000000000000000000000000000000000000"),
+        hex!("e0eb7a7c3b41b8ae1656e3faf19fc46ada098deb9c32b1fd866205165f49b800"),
+        hex!("5f9c95bca3508c24b1d0b1559c83ef5b04445cc4581c8e86d8224eddd09f1157"),
+        hex!("ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f"),
+        hex!("edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f"),
+        hex!("eeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f"),
+    ];
+
+    for point in low_order {
+        assert_eq!(
+            secret.diffie_hellman(&PublicKey::new(point)).err(),
+            Some(Error::LowOrderPoint)
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn x25519_random_secrets_agree() {
+    use purecrypt::chacha::{ChaCha20Rng, Seed};
+    use purecrypt::x25519::EphemeralSecret;
+
+    let mut rng = ChaCha20Rng::from_seed(&Seed::new([7; 32]));
+
+    let alice = EphemeralSecret::random_from_rng(&mut rng);
+    let bob = StaticSecret::random_from_rng(&mut rng);
+
+    let alice_public = alice.public_key();
+    let bob_public = bob.public_key();
+
+    let alice_shared = alice.diffie_hellman(&bob_public).unwrap();
+    let bob_shared = bob.diffie_hellman(&alice_public).unwrap();
+
+    assert_eq!(alice_shared.bytes(), bob_shared.bytes());
+}